@@ -22,7 +22,7 @@ use criterion::Criterion;
 extern crate arrow;
 
 use arrow::array::*;
-use arrow::compute::concat;
+use arrow::compute::{concat, concat_dictionaries_merge};
 use arrow::datatypes::*;
 use arrow::util::bench_util::*;
 
@@ -45,12 +45,46 @@ fn add_benchmark(c: &mut Criterion) {
         b.iter(|| bench_concat(&v1, &v2))
     });
 
+    let v1 = create_primitive_array::<Int64Type>(8192, 0.95);
+    let v2 = create_primitive_array::<Int64Type>(8192, 0.95);
+    c.bench_function("concat i64 sparse nulls 8192", |b| {
+        b.iter(|| bench_concat(&v1, &v2))
+    });
+
     let small_array = create_primitive_array::<Int32Type>(4, 0.0);
     let arrays: Vec<_> = (0..1024).map(|_| &small_array as &dyn Array).collect();
     c.bench_function("concat 1024 arrays i32 4", |b| {
         b.iter(|| bench_concat_arrays(&arrays))
     });
 
+    let small_array = create_primitive_array::<Int32Type>(2, 0.0);
+    let arrays: Vec<_> = (0..10_000).map(|_| &small_array as &dyn Array).collect();
+    c.bench_function("concat 10000 arrays i32 2", |b| {
+        b.iter(|| bench_concat_arrays(&arrays))
+    });
+
+    let small_array = create_primitive_array::<Int32Type>(2, 0.5);
+    let arrays: Vec<_> = (0..10_000).map(|_| &small_array as &dyn Array).collect();
+    c.bench_function("concat 10000 arrays i32 nulls 2", |b| {
+        b.iter(|| bench_concat_arrays(&arrays))
+    });
+
+    let v1 = create_boolean_array(1024, 0.0, 0.5);
+    let v2 = create_boolean_array(1024, 0.0, 0.5);
+    c.bench_function("concat boolean 1024", |b| b.iter(|| bench_concat(&v1, &v2)));
+
+    let v1 = create_boolean_array(1024, 0.5, 0.5);
+    let v2 = create_boolean_array(1024, 0.5, 0.5);
+    c.bench_function("concat boolean nulls 1024", |b| {
+        b.iter(|| bench_concat(&v1, &v2))
+    });
+
+    let v1 = create_boolean_array(1024, 0.0, 0.5).slice(3, 1019);
+    let v2 = create_boolean_array(1024, 0.0, 0.5).slice(5, 1017);
+    c.bench_function("concat boolean 1024 unaligned", |b| {
+        b.iter(|| bench_concat(v1.as_ref(), v2.as_ref()))
+    });
+
     let v1 = create_string_array::<i32>(1024, 0.0);
     let v2 = create_string_array::<i32>(1024, 0.0);
     c.bench_function("concat str 1024", |b| b.iter(|| bench_concat(&v1, &v2)));
@@ -60,6 +94,24 @@ fn add_benchmark(c: &mut Criterion) {
     c.bench_function("concat str nulls 1024", |b| {
         b.iter(|| bench_concat(&v1, &v2))
     });
+
+    // the large dictionary is the base of the merge wherever it is
+    let large = create_string_dict_array::<Int32Type>(4096, 0.0);
+    let small = create_string_dict_array::<Int32Type>(64, 0.0);
+    c.bench_function("concat dictionaries merge large first", |b| {
+        b.iter(|| {
+            criterion::black_box(
+                concat_dictionaries_merge::<Int32Type>(&[&large, &small]).unwrap(),
+            )
+        })
+    });
+    c.bench_function("concat dictionaries merge large last", |b| {
+        b.iter(|| {
+            criterion::black_box(
+                concat_dictionaries_merge::<Int32Type>(&[&small, &large]).unwrap(),
+            )
+        })
+    });
 }
 
 criterion_group!(benches, add_benchmark);
@@ -329,7 +329,7 @@ pub enum Capacities {
     /// * the capacity of the array offsets
     /// * the capacity of the binary/ str buffer
     Binary(usize, Option<usize>),
-    /// List and LargeList data types
+    /// List, LargeList and FixedSizeList data types
     /// Define
     /// * the capacity of the array offsets
     /// * the capacity of the child data
@@ -391,7 +391,16 @@ impl<'a> MutableArrayData<'a> {
                 array_capacity = *capacity;
                 preallocate_offset_and_binary_buffer::<i32>(*capacity, *value_cap)
             }
-            (_, Capacities::Array(capacity)) => {
+            (
+                DataType::List(_)
+                | DataType::LargeList(_)
+                | DataType::Map(_, _)
+                | DataType::FixedSizeList(_, _),
+                Capacities::List(capacity, _),
+            )
+            | (DataType::Struct(_), Capacities::Struct(capacity, _))
+            | (DataType::Dictionary(_, _), Capacities::Dictionary(capacity, _))
+            | (_, Capacities::Array(capacity)) => {
                 array_capacity = *capacity;
                 new_buffers(data_type, *capacity)
             }
@@ -489,12 +498,23 @@ impl<'a> MutableArrayData<'a> {
                     })
                     .collect::<Vec<_>>(),
             },
-            DataType::FixedSizeList(_, _) => {
+            DataType::FixedSizeList(_, size) => {
                 let childs = arrays
                     .iter()
                     .map(|array| &array.child_data()[0])
                     .collect::<Vec<_>>();
-                vec![MutableArrayData::new(childs, use_nulls, array_capacity)]
+
+                // every slot holds `size` child values
+                let capacities = match capacities {
+                    Capacities::List(_, Some(ref child_capacities)) => {
+                        child_capacities.as_ref().clone()
+                    }
+                    _ => Capacities::Array(array_capacity * *size as usize),
+                };
+
+                vec![MutableArrayData::with_capacities(
+                    childs, use_nulls, capacities,
+                )]
             }
             DataType::Union(fields, _, _) => (0..fields.len())
                 .map(|i| {
@@ -518,9 +538,6 @@ impl<'a> MutableArrayData<'a> {
                 match dict_concat {
                     false => (Some(arrays[0].child_data()[0].clone()), false),
                     true => {
-                        if let Capacities::Dictionary(_, _) = capacities {
-                            panic!("dictionary capacity not yet supported")
-                        }
                         let dictionaries: Vec<_> =
                             arrays.iter().map(|array| &array.child_data()[0]).collect();
                         let lengths: Vec<_> = dictionaries
@@ -529,8 +546,16 @@ impl<'a> MutableArrayData<'a> {
                             .collect();
                         let capacity = lengths.iter().sum();
 
-                        let mut mutable =
-                            MutableArrayData::new(dictionaries, false, capacity);
+                        let mut mutable = match &capacities {
+                            Capacities::Dictionary(_, Some(values_capacities)) => {
+                                MutableArrayData::with_capacities(
+                                    dictionaries,
+                                    false,
+                                    values_capacities.as_ref().clone(),
+                                )
+                            }
+                            _ => MutableArrayData::new(dictionaries, false, capacity),
+                        };
 
                         for (i, len) in lengths.iter().enumerate() {
                             mutable.extend(i, 0, *len)
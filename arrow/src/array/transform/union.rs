@@ -21,6 +21,8 @@ use super::{Extend, _MutableArrayData};
 
 pub(super) fn build_extend_sparse(array: &ArrayData) -> Extend {
     let type_ids = array.buffer::<i8>(0);
+    // the children of a sparse union are not sliced with it
+    let offset = array.offset();
 
     Box::new(
         move |mutable: &mut _MutableArrayData, index: usize, start: usize, len: usize| {
@@ -29,10 +31,9 @@ pub(super) fn build_extend_sparse(array: &ArrayData) -> Extend {
                 .buffer1
                 .extend_from_slice(&type_ids[start..start + len]);
 
-            mutable
-                .child_data
-                .iter_mut()
-                .for_each(|child| child.extend(index, start, start + len))
+            mutable.child_data.iter_mut().for_each(|child| {
+                child.extend(index, offset + start, offset + start + len)
+            })
         },
     )
 }
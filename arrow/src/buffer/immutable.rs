@@ -170,6 +170,18 @@ impl Buffer {
         }
     }
 
+    /// Returns a [MutableBuffer] reusing the memory of this buffer if it is the
+    /// only reference to it, was allocated by a [MutableBuffer] and is not a
+    /// slice of it, and returns this buffer back otherwise.
+    pub(crate) fn into_mutable(self) -> Result<MutableBuffer, Self> {
+        if self.offset != 0 {
+            return Err(self);
+        }
+        Arc::try_unwrap(self.data)
+            .and_then(|bytes| MutableBuffer::from_bytes(bytes).map_err(Arc::new))
+            .map_err(|data| Buffer { data, offset: 0 })
+    }
+
     /// Returns a pointer to the start of this buffer.
     ///
     /// Note that this should be used cautiously, and the returned pointer should not be
@@ -401,6 +413,35 @@ mod tests {
         assert_eq!([0, 1, 2, 3, 4], buf2.as_slice());
     }
 
+    #[test]
+    fn test_into_mutable() {
+        let buf = Buffer::from(&[2, 4, 6]);
+        let ptr = buf.as_ptr();
+        let mut mutable = buf.into_mutable().unwrap();
+        assert_eq!(mutable.as_ptr(), ptr as *mut u8);
+        mutable.push(8u8);
+        assert_eq!(Buffer::from(mutable).as_slice(), &[2, 4, 6, 8]);
+
+        // shared buffers and slices are given back
+        let buf = Buffer::from(&[2, 4, 6]);
+        let shared = buf.clone();
+        let buf = buf.into_mutable().unwrap_err();
+        assert_eq!(buf, shared);
+        drop(shared);
+        assert!(buf.slice(1).into_mutable().is_err());
+
+        // as are buffers allocated outside of arrow
+        let mut vec = vec![1u8, 2];
+        let buf = unsafe {
+            Buffer::from_custom_allocation(
+                NonNull::new_unchecked(vec.as_mut_ptr()),
+                vec.len(),
+                Arc::new(vec),
+            )
+        };
+        assert_eq!(buf.into_mutable().unwrap_err().as_slice(), &[1, 2]);
+    }
+
     #[test]
     fn test_slice() {
         let buf = Buffer::from(&[2, 4, 6, 8, 10]);
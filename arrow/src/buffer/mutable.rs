@@ -273,6 +273,22 @@ impl MutableBuffer {
         Buffer::from_bytes(bytes)
     }
 
+    /// Takes ownership of the memory of `bytes` if it was allocated by a
+    /// [MutableBuffer], returning it back otherwise
+    pub(super) fn from_bytes(bytes: Bytes) -> Result<Self, Bytes> {
+        let capacity = match bytes.deallocation() {
+            Deallocation::Arrow(capacity) => *capacity,
+            Deallocation::Custom(_) => return Err(bytes),
+        };
+        let buffer = Self {
+            data: bytes.ptr(),
+            len: bytes.len(),
+            capacity,
+        };
+        std::mem::forget(bytes);
+        Ok(buffer)
+    }
+
     /// View this buffer asa slice of a specific type.
     ///
     /// # Safety
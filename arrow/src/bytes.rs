@@ -91,6 +91,11 @@ impl Bytes {
         self.ptr
     }
 
+    /// Returns how this region is deallocated
+    pub(crate) fn deallocation(&self) -> &Deallocation {
+        &self.deallocation
+    }
+
     pub fn capacity(&self) -> usize {
         match self.deallocation {
             Deallocation::Arrow(capacity) => capacity,
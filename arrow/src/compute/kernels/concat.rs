@@ -31,25 +31,104 @@
 //! ```
 
 use crate::array::*;
-use crate::datatypes::DataType;
+use crate::buffer::{Buffer, MutableBuffer};
+use crate::compute::kernels::cast::{cast, cast_with_options, CastOptions};
+use crate::compute::kernels::filter::{prep_null_mask_filter, SlicesIterator};
+use crate::compute::kernels::sort::{sort_to_indices, SortOptions};
+use crate::compute::kernels::take::take;
+use crate::datatypes::{
+    ArrowDictionaryKeyType, ArrowNativeType, ArrowPrimitiveType, DataType, Field,
+    Int32Type, Schema, SchemaRef, UInt32Type, UnionMode,
+};
 use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+use crate::util::{bit_mask::set_bits, bit_util};
+use num::Float;
+use std::cmp::Ordering;
+use std::ops::Range;
+use std::sync::Arc;
 
-fn compute_str_values_length<Offset: OffsetSizeTrait>(arrays: &[&ArrayData]) -> usize {
+fn compute_binary_values_length<Offset: OffsetSizeTrait>(arrays: &[&ArrayData]) -> usize {
     arrays
         .iter()
         .map(|&data| {
-            // get the length of the value buffer
-            let buf_len = data.buffers()[1].len();
-            // find the offset of the buffer
             // this returns a slice of offsets, starting from the offset of the array
-            // so we can take the first value
-            let offset = data.buffer::<Offset>(0)[0];
-            buf_len - offset.to_usize().unwrap()
+            // so only the values referenced by the array are counted
+            let offsets = data.buffer::<Offset>(0);
+            (offsets[data.len()] - offsets[0]).to_usize().unwrap()
         })
         .sum()
 }
 
+/// Returns the child values referenced by each of the list `arrays`
+fn list_values<Offset: OffsetSizeTrait>(arrays: &[&ArrayData]) -> Vec<ArrayData> {
+    arrays
+        .iter()
+        .filter(|data| !data.is_empty())
+        .map(|data| {
+            // offsets are sliced from the offset of the array, so this only
+            // selects the child values referenced by the array
+            let offsets = data.buffer::<Offset>(0);
+            let start = offsets[0].to_usize().unwrap();
+            let end = offsets[data.len()].to_usize().unwrap();
+            data.child_data()[0].slice(start, end - start)
+        })
+        .collect()
+}
+
 /// Concatenate multiple [Array] of the same type into a single [ArrayRef].
+///
+/// Use [concat_array_refs] to concatenate a slice of [ArrayRef], such as a
+/// `Vec<ArrayRef>`, without collecting references to them first.
+///
+/// # Dictionaries
+///
+/// [DictionaryArray]s are concatenated by appending their keys. If every
+/// input shares the same dictionary values (as determined by
+/// [ArrayData::ptr_eq]), those values are reused as-is and only the keys are
+/// copied. Otherwise the values of all inputs are concatenated, without
+/// deduplication, and the keys of each input are offset to point into its
+/// portion of the merged values. An error is returned if the merged values
+/// cannot be indexed by the key type.
+///
+/// As the merged values are not sorted, the result is never flagged as
+/// ordered, see [DictionaryArray::is_ordered]. [concat_batches] sorts the
+/// merged values of the columns whose [Field](crate::datatypes::Field) is
+/// ordered.
+///
+/// # Null arrays
+///
+/// Arrays of [DataType::Null] are treated as that many nulls of the type of
+/// the other arrays, which must still all be of the same type. If all of the
+/// arrays are of [DataType::Null], a [NullArray] is returned.
+///
+/// # Alignment
+///
+/// The inputs may have buffers of any alignment, as long as it is valid for
+/// their type, for example memory mapped from a file. The buffers allocated
+/// for the result are always aligned to [ALIGNMENT](crate::alloc::ALIGNMENT).
+/// Only the buffers the result shares with the inputs without copying, when
+/// a single input is not empty or when the inputs are adjacent slices of the
+/// same array, keep the alignment of the inputs.
+///
+/// # Capacity
+///
+/// The buffers of the result are sized upfront from the inputs, including the
+/// value bytes of variable sized types. This looks through dictionaries to
+/// their value type. Extension types are represented by their storage
+/// [DataType], with the extension name kept in the [Field](crate::datatypes::Field)
+/// metadata, and so are sized like any other array of that type.
+///
+/// Like every [MutableBuffer], the buffers are padded to a multiple of 64
+/// bytes, as recommended by the Arrow format, so their capacity can exceed the
+/// exact size of the result by up to 63 bytes each.
+///
+/// # Validation
+///
+/// As the inputs are valid, the result is built without validating it. When
+/// the `force_validate` feature is enabled, it is instead fully validated with
+/// [ArrayData::validate_full], like any other [ArrayData], which panics on
+/// corrupt buffers or offsets. This is only meant for testing and fuzzing.
 pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef> {
     if arrays.is_empty() {
         return Err(ArrowError::ComputeError(
@@ -60,513 +139,8043 @@ pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef> {
         return Ok(array.slice(0, array.len()));
     }
 
-    if arrays
-        .iter()
-        .any(|array| array.data_type() != arrays[0].data_type())
-    {
-        return Err(ArrowError::InvalidArgumentError(
-            "It is not possible to concatenate arrays of different data types."
-                .to_string(),
-        ));
+    let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    let expanded = expand_null_arrays(&arrays);
+    let arrays = match &expanded {
+        Some(expanded) => expanded.iter().collect(),
+        None => arrays,
+    };
+    check_data_types(&arrays)?;
+
+    Ok(make_array(concat_data(&arrays)?))
+}
+
+/// How [concat_with_metadata_policy] resolves the metadata of the nested
+/// fields of its inputs, such as the fields of a struct or the item field of a
+/// list, when it differs between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataPolicy {
+    /// The nested fields of the result have the metadata of the first input
+    KeepFirst,
+    /// The metadata of the nested fields of all inputs must be equal, as in
+    /// [concat], otherwise an error is returned
+    RequireEqual,
+    /// The metadata of the nested fields is removed from the result
+    Drop,
+}
+
+/// Concatenate multiple [Array] of the same type into a single [ArrayRef],
+/// resolving the differences in the metadata of their nested fields with
+/// `policy`.
+///
+/// Apart from the metadata of their nested fields, which is resolved by
+/// `policy`, the data types of `arrays` must be equal as in [concat].
+///
+/// ```
+/// use arrow::array::{Array, ArrayRef, Int32Array, StructArray};
+/// use arrow::compute::{concat_with_metadata_policy, MetadataPolicy};
+/// use arrow::datatypes::{DataType, Field};
+/// use std::collections::BTreeMap;
+/// use std::sync::Arc;
+///
+/// let field = Field::new("a", DataType::Int32, false);
+/// let metadata = BTreeMap::from([("k".to_string(), "v".to_string())]);
+/// let struct_array = |field: Field| {
+///     StructArray::from(vec![(field, Arc::new(Int32Array::from(vec![1])) as ArrayRef)])
+/// };
+/// let array_1 = struct_array(field.clone().with_metadata(Some(metadata)));
+/// let array_2 = struct_array(field.clone());
+///
+/// let array = concat_with_metadata_policy(&[&array_1, &array_2], MetadataPolicy::Drop).unwrap();
+/// assert_eq!(array.data_type(), &DataType::Struct(vec![field]));
+/// ```
+pub fn concat_with_metadata_policy(
+    arrays: &[&dyn Array],
+    policy: MetadataPolicy,
+) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let data_type = match policy {
+        MetadataPolicy::RequireEqual => return concat(arrays),
+        MetadataPolicy::KeepFirst => arrays[0].data_type().clone(),
+        MetadataPolicy::Drop => without_field_metadata(arrays[0].data_type()),
+    };
+    let stripped = without_field_metadata(&data_type);
+
+    // arrays whose data type still differs are left for concat to report
+    let arrays = arrays
+        .iter()
+        .map(|array| {
+            match array.data_type() == &data_type
+                || without_field_metadata(array.data_type()) != stripped
+            {
+                true => array.slice(0, array.len()),
+                false => make_array(with_data_type(array.data(), &data_type)),
+            }
+        })
+        .collect::<Vec<_>>();
+    concat(&arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>())
+}
+
+/// Returns `data_type` with the metadata of all its nested fields removed
+fn without_field_metadata(data_type: &DataType) -> DataType {
+    let field = |field: &Field| {
+        let data_type = without_field_metadata(field.data_type());
+        // fields are created without metadata
+        Field::new_dict(
+            field.name(),
+            data_type,
+            field.is_nullable(),
+            field.dict_id().unwrap_or_default(),
+            field.dict_is_ordered().unwrap_or_default(),
+        )
+    };
+    match data_type {
+        DataType::List(f) => DataType::List(Box::new(field(f))),
+        DataType::LargeList(f) => DataType::LargeList(Box::new(field(f))),
+        DataType::FixedSizeList(f, size) => {
+            DataType::FixedSizeList(Box::new(field(f)), *size)
+        }
+        DataType::Map(f, sorted) => DataType::Map(Box::new(field(f)), *sorted),
+        DataType::Struct(fields) => DataType::Struct(fields.iter().map(field).collect()),
+        DataType::Union(fields, type_ids, mode) => DataType::Union(
+            fields.iter().map(field).collect(),
+            type_ids.clone(),
+            mode.clone(),
+        ),
+        DataType::Dictionary(key_type, value_type) => DataType::Dictionary(
+            key_type.clone(),
+            Box::new(without_field_metadata(value_type)),
+        ),
+        data_type => data_type.clone(),
+    }
+}
+
+/// Returns `data` with the data type `data_type`, which must only differ from
+/// the data type of `data` by the metadata of its nested fields
+fn with_data_type(data: &ArrayData, data_type: &DataType) -> ArrayData {
+    let child_types = match data_type {
+        DataType::List(f)
+        | DataType::LargeList(f)
+        | DataType::FixedSizeList(f, _)
+        | DataType::Map(f, _) => vec![f.data_type()],
+        DataType::Struct(fields) | DataType::Union(fields, _, _) => {
+            fields.iter().map(|f| f.data_type()).collect()
+        }
+        DataType::Dictionary(_, value_type) => vec![value_type.as_ref()],
+        _ => vec![],
+    };
+    let child_data = data
+        .child_data()
+        .iter()
+        .zip(child_types)
+        .map(|(child, data_type)| with_data_type(child, data_type))
+        .collect();
+
+    let builder = ArrayData::builder(data_type.clone())
+        .len(data.len())
+        .offset(data.offset())
+        .null_count(data.null_count())
+        .null_bit_buffer(data.null_buffer().cloned())
+        .buffers(data.buffers().to_vec())
+        .child_data(child_data);
+    // Safety: only the metadata of the nested fields of the data type changed
+    unsafe { builder.build_unchecked() }
+}
+
+/// Concatenate multiple [Array] of the same type into a single [ArrayRef],
+/// without checking that they are of the same type.
+///
+/// This behaves like [concat], without comparing the [DataType] of every
+/// input to the first one, which can be costly for many inputs of nested
+/// types.
+///
+/// # Safety
+///
+/// All of `arrays` must have the same [DataType], otherwise the behavior is
+/// undefined.
+pub unsafe fn concat_unchecked(arrays: &[&dyn Array]) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    } else if arrays.len() == 1 {
+        let array = arrays[0];
+        return Ok(array.slice(0, array.len()));
+    }
+
+    let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    Ok(make_array(concat_data(&arrays)?))
+}
+
+/// Concatenate multiple [Array] of the same type into a single [ArrayRef],
+/// allocating room for at least `capacity` slots.
+///
+/// This is useful to over-allocate the result of [concat] when more values
+/// will later be appended to its buffers. A `capacity` smaller than the total
+/// length of `arrays` is clamped up to it. Unlike [concat], the result is
+/// always copied, even for a single input.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array};
+/// use arrow::compute::concat_with_capacity;
+///
+/// let array = concat_with_capacity(
+///     &[&Int32Array::from(vec![1, 2]), &Int32Array::from(vec![3])],
+///     1024,
+/// ).unwrap();
+/// assert_eq!(array.len(), 3);
+/// assert!(array.data().buffers()[0].capacity() >= 1024 * 4);
+/// ```
+pub fn concat_with_capacity(arrays: &[&dyn Array], capacity: usize) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    let expanded = expand_null_arrays(&arrays);
+    let arrays = match &expanded {
+        Some(expanded) => expanded.iter().collect(),
+        None => arrays,
+    };
+    check_data_types(&arrays)?;
+
+    let capacities = match concat_capacities(&arrays, 1)? {
+        Capacities::Binary(len, values) => Capacities::Binary(len.max(capacity), values),
+        Capacities::List(len, child) => Capacities::List(len.max(capacity), child),
+        Capacities::Struct(len, children) => {
+            Capacities::Struct(len.max(capacity), children)
+        }
+        Capacities::Dictionary(len, values) => {
+            Capacities::Dictionary(len.max(capacity), values)
+        }
+        Capacities::Array(len) => Capacities::Array(len.max(capacity)),
+    };
+    let mut mutable =
+        MutableArrayData::with_capacities(arrays.clone(), false, capacities);
+
+    for (i, array) in arrays.iter().enumerate() {
+        mutable.extend(i, 0, array.len())
+    }
+
+    Ok(make_array(mutable.freeze()))
+}
+
+/// If some, but not all, of `arrays` are of [DataType::Null], returns `arrays`
+/// with those replaced by arrays of nulls of the type of the first other array
+fn expand_null_arrays(arrays: &[&ArrayData]) -> Option<Vec<ArrayData>> {
+    let data_type = arrays
+        .iter()
+        .map(|array| array.data_type())
+        .find(|data_type| **data_type != DataType::Null)?;
+    if arrays
+        .iter()
+        .all(|array| array.data_type() != &DataType::Null)
+    {
+        return None;
+    }
+
+    let arrays = arrays
+        .iter()
+        .map(|array| match array.data_type() {
+            DataType::Null => new_null_array(data_type, array.len()).data().clone(),
+            _ => (*array).clone(),
+        })
+        .collect();
+    Some(arrays)
+}
+
+/// Returns an estimate of the number of bytes that the buffers of the [concat]
+/// of `arrays` will occupy, without concatenating them.
+///
+/// This sums the sizes of the values, offsets and validity buffers of the
+/// result, down to its child data. It does not account for the padding of
+/// buffers to 64 bytes, nor for the memory used by the array structs
+/// themselves. The size of union arrays is estimated from the buffers of the
+/// inputs.
+///
+/// ```
+/// use arrow::array::{Array, StringArray};
+/// use arrow::compute::concat_output_size;
+///
+/// let size = concat_output_size(&[
+///     &StringArray::from(vec!["hello", "world"]),
+///     &StringArray::from(vec!["!"]),
+/// ]).unwrap();
+/// // 4 offsets of 4 bytes and 11 bytes of values
+/// assert_eq!(size, 27);
+/// ```
+pub fn concat_output_size(arrays: &[&dyn Array]) -> Result<usize> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    check_data_types(&arrays)?;
+
+    Ok(output_size(&arrays))
+}
+
+/// Returns the number of value bytes referenced by the string or binary
+/// `arrays`, that is the size of the values buffer of their [concat].
+///
+/// Only the bytes within the slice of each array are counted. An error is
+/// returned if any of `arrays` is not a string or binary array with offsets
+/// of type `Offset`.
+///
+/// ```
+/// use arrow::array::{Array, StringArray};
+/// use arrow::compute::string_values_byte_size;
+///
+/// let a = StringArray::from(vec!["hello", "world"]);
+/// let b = StringArray::from(vec!["a", "bc", "def"]).slice(1, 2);
+/// let size = string_values_byte_size::<i32>(&[&a, b.as_ref()]).unwrap();
+/// assert_eq!(size, 15);
+/// ```
+pub fn string_values_byte_size<Offset: OffsetSizeTrait>(
+    arrays: &[&dyn Array],
+) -> Result<usize> {
+    let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    if let Some(array) = arrays.iter().find(|array| {
+        !matches!(
+            (Offset::IS_LARGE, array.data_type()),
+            (false, DataType::Utf8 | DataType::Binary)
+                | (true, DataType::LargeUtf8 | DataType::LargeBinary)
+        )
+    }) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "string_values_byte_size expects {} arrays, got {:?}",
+            match Offset::IS_LARGE {
+                true => "LargeUtf8 or LargeBinary",
+                false => "Utf8 or Binary",
+            },
+            array.data_type()
+        )));
+    }
+
+    Ok(compute_binary_values_length::<Offset>(&arrays))
+}
+
+/// Returns the number of bytes of the buffers of the concatenation of `arrays`
+fn output_size(arrays: &[&ArrayData]) -> usize {
+    let len = arrays.iter().map(|array| array.len()).sum::<usize>();
+    let children_size = |children: Vec<ArrayData>| match children.is_empty() {
+        true => 0,
+        false => output_size(&children.iter().collect::<Vec<_>>()),
+    };
+
+    let nulls_size = match arrays.iter().any(|array| array.null_count() > 0) {
+        true => bit_util::ceil(len, 8),
+        false => 0,
+    };
+    let values_size = match arrays[0].data_type() {
+        DataType::Null => 0,
+        DataType::Boolean => bit_util::ceil(len, 8),
+        DataType::Utf8 | DataType::Binary => {
+            (len + 1) * 4 + compute_binary_values_length::<i32>(arrays)
+        }
+        DataType::LargeUtf8 | DataType::LargeBinary => {
+            (len + 1) * 8 + compute_binary_values_length::<i64>(arrays)
+        }
+        DataType::List(_) | DataType::Map(_, _) => {
+            (len + 1) * 4 + children_size(list_values::<i32>(arrays))
+        }
+        DataType::LargeList(_) => {
+            (len + 1) * 8 + children_size(list_values::<i64>(arrays))
+        }
+        DataType::FixedSizeList(_, size) => {
+            let size = *size as usize;
+            children_size(
+                arrays
+                    .iter()
+                    .map(|array| {
+                        array.child_data()[0]
+                            .slice(array.offset() * size, array.len() * size)
+                    })
+                    .collect(),
+            )
+        }
+        DataType::Struct(fields) => (0..fields.len())
+            .map(|i| {
+                children_size(
+                    arrays
+                        .iter()
+                        .map(|array| array.child_data()[i].slice(0, array.len()))
+                        .collect(),
+                )
+            })
+            .sum(),
+        DataType::Dictionary(key_type, _) => {
+            let shared = arrays
+                .windows(2)
+                .all(|a| a[0].child_data()[0].ptr_eq(&a[1].child_data()[0]));
+            let values = match shared {
+                true => vec![&arrays[0].child_data()[0]],
+                false => arrays.iter().map(|array| &array.child_data()[0]).collect(),
+            };
+            len * fixed_byte_width(key_type).unwrap() + output_size(&values)
+        }
+        DataType::Union(_, _, _) => arrays
+            .iter()
+            .map(|array| array.get_buffer_memory_size())
+            .sum(),
+        data_type => len * fixed_byte_width(data_type).unwrap_or_default(),
+    };
+
+    nulls_size + values_size
+}
+
+/// Concatenate multiple [Array] of the same type into a single [ArrayRef], also
+/// returning the offset at which each of `arrays` starts in the result.
+///
+/// The offsets are the prefix sums of the lengths of `arrays`, so the rows of
+/// `arrays[i]` are at `offsets[i]..offsets[i] + arrays[i].len()` in the result.
+///
+/// ```
+/// use arrow::array::Int32Array;
+/// use arrow::compute::concat_with_offsets;
+///
+/// let (array, offsets) = concat_with_offsets(&[
+///     &Int32Array::from(vec![1, 2]),
+///     &Int32Array::from(vec![3]),
+///     &Int32Array::from(vec![4, 5]),
+/// ]).unwrap();
+/// assert_eq!(array.len(), 5);
+/// assert_eq!(offsets, vec![0, 2, 3]);
+/// ```
+pub fn concat_with_offsets(arrays: &[&dyn Array]) -> Result<(ArrayRef, Vec<usize>)> {
+    let array = concat(arrays)?;
+    let offsets = arrays
+        .iter()
+        .scan(0, |offset, array| {
+            let start = *offset;
+            *offset += array.len();
+            Some(start)
+        })
+        .collect();
+
+    Ok((array, offsets))
+}
+
+/// Concatenate multiple [GenericStringArray] into a single one, also returning
+/// the byte range that the values of each of `arrays` span in the value buffer
+/// of the result.
+///
+/// The ranges are the prefix sums of the value lengths of `arrays`, starting
+/// from the first offset of the result, so they tile the values of the result
+/// in order without gaps. Empty inputs have an empty range.
+///
+/// ```
+/// use arrow::array::{Array, StringArray};
+/// use arrow::compute::concat_utf8_with_byte_ranges;
+///
+/// let (array, ranges) = concat_utf8_with_byte_ranges(&[
+///     &StringArray::from(vec!["hello", "world"]),
+///     &StringArray::from(vec!["!"]),
+/// ]).unwrap();
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(ranges, vec![0..10, 10..11]);
+/// ```
+pub fn concat_utf8_with_byte_ranges<Offset: OffsetSizeTrait>(
+    arrays: &[&GenericStringArray<Offset>],
+) -> Result<(GenericStringArray<Offset>, Vec<Range<usize>>)> {
+    let array = concat(&arrays.iter().map(|a| *a as &dyn Array).collect::<Vec<_>>())?;
+    let array = GenericStringArray::<Offset>::from(array.data().clone());
+
+    // the result is not copied if only one of `arrays` is not empty, so its
+    // values do not necessarily start at the beginning of the value buffer
+    let start = array.value_offsets()[0].to_usize().unwrap();
+    let ranges = arrays
+        .iter()
+        .scan(start, |offset, array| {
+            let start = *offset;
+            *offset += compute_binary_values_length::<Offset>(&[array.data()]);
+            Some(start..*offset)
+        })
+        .collect();
+
+    Ok((array, ranges))
+}
+
+/// Statistics about the result of [concat_with_stats]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConcatStats {
+    /// The number of null slots of the result
+    pub null_count: usize,
+    /// Whether the result has any null slots
+    pub has_nulls: bool,
+}
+
+/// Concatenate multiple [Array] of the same type into a single [ArrayRef], also
+/// returning [ConcatStats] about the result.
+///
+/// The statistics are accumulated from the null counts of `arrays`, without
+/// scanning the result.
+///
+/// ```
+/// use arrow::array::Int32Array;
+/// use arrow::compute::{concat_with_stats, ConcatStats};
+///
+/// let (array, stats) = concat_with_stats(&[
+///     &Int32Array::from(vec![Some(1), None]),
+///     &Int32Array::from(vec![None, Some(4)]),
+/// ]).unwrap();
+/// assert_eq!(array.len(), 4);
+/// assert_eq!(stats, ConcatStats { null_count: 2, has_nulls: true });
+/// ```
+pub fn concat_with_stats(arrays: &[&dyn Array]) -> Result<(ArrayRef, ConcatStats)> {
+    let array = concat(arrays)?;
+    let null_count = arrays.iter().map(|array| array.null_count()).sum();
+    let stats = ConcatStats {
+        null_count,
+        has_nulls: null_count > 0,
+    };
+
+    Ok((array, stats))
+}
+
+/// Concatenate multiple floating point [PrimitiveArray] into a single
+/// [ArrayRef], also returning whether any of its valid slots is NaN.
+///
+/// NaN values in null slots are ignored. Each value is checked for NaN as it
+/// is copied to the result, so the inputs are only read once, and the values
+/// of inputs without nulls are checked without looking up their validity.
+///
+/// ```
+/// use arrow::array::Float64Array;
+/// use arrow::compute::concat_with_float_stats;
+///
+/// let (array, has_nan) = concat_with_float_stats(&[
+///     &Float64Array::from(vec![1.0, 2.0]),
+///     &Float64Array::from(vec![f64::NAN]),
+/// ]).unwrap();
+/// assert_eq!(array.len(), 3);
+/// assert!(has_nan);
+/// ```
+pub fn concat_with_float_stats<T>(
+    arrays: &[&PrimitiveArray<T>],
+) -> Result<(ArrayRef, bool)>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Float,
+{
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+    let data = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    check_data_types(&data)?;
+
+    let len = arrays.iter().map(|array| array.len()).sum::<usize>();
+    let mut values = MutableBuffer::new(len * std::mem::size_of::<T::Native>());
+    let mut has_nan = false;
+    for array in arrays {
+        match array.null_count() {
+            0 => values.extend(array.values().iter().map(|value| {
+                has_nan |= value.is_nan();
+                *value
+            })),
+            _ => values.extend(array.values().iter().enumerate().map(|(i, value)| {
+                has_nan |= value.is_nan() && array.is_valid(i);
+                *value
+            })),
+        }
+    }
+
+    let nulls = concat_null_buffers(&data, len);
+    let builder = ArrayData::builder(data[0].data_type().clone())
+        .len(len)
+        .null_bit_buffer(nulls)
+        .add_buffer(values.into());
+    // Safety: the values and null buffers are sized from the lengths of the
+    // inputs, which all share the same primitive data type
+    Ok((make_array(unsafe { builder.build_unchecked() }), has_nan))
+}
+
+/// Concatenate multiple [Array] of the same type into a single [ArrayRef], also
+/// returning an [Int32Array] with the index in `arrays` each row came from.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array};
+/// use arrow::compute::concat_with_source_ids;
+///
+/// let (array, source_ids) = concat_with_source_ids(&[
+///     &Int32Array::from(vec![1, 2]),
+///     &Int32Array::from(vec![3]),
+/// ]).unwrap();
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(source_ids, Int32Array::from(vec![0, 0, 1]));
+/// ```
+pub fn concat_with_source_ids(arrays: &[&dyn Array]) -> Result<(ArrayRef, Int32Array)> {
+    let array = concat(arrays)?;
+    let source_ids = Int32Array::from_iter_values(
+        arrays
+            .iter()
+            .enumerate()
+            .flat_map(|(i, array)| std::iter::repeat(i as i32).take(array.len())),
+    );
+
+    Ok((array, source_ids))
+}
+
+/// Concatenate multiple [Array] of the same type into a single [ArrayRef],
+/// calling `on_extend` for each region of the result copied from one of
+/// `arrays`.
+///
+/// `on_extend(i, start, len)` is called as the result is built, right after
+/// the `len` rows of the input at index `i` are copied to the result at row
+/// `start`, so in the order of the result. Empty inputs are skipped. This
+/// allows tracking the provenance of the rows of the result, for example to
+/// carry state kept alongside `arrays`. It is not called if `arrays` cannot be
+/// concatenated.
+///
+/// Unlike [concat], which has faster paths for some inputs, every input is
+/// copied with [MutableArrayData] so that `on_extend` follows each copy.
+///
+/// ```
+/// use arrow::array::Int32Array;
+/// use arrow::compute::concat_with_callback;
+///
+/// let mut regions = vec![];
+/// let array = concat_with_callback(
+///     &[&Int32Array::from(vec![1, 2]), &Int32Array::from(vec![3])],
+///     |i, start, len| regions.push((i, start, len)),
+/// ).unwrap();
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(regions, vec![(0, 0, 2), (1, 2, 1)]);
+/// ```
+pub fn concat_with_callback<F>(
+    arrays: &[&dyn Array],
+    mut on_extend: F,
+) -> Result<ArrayRef>
+where
+    F: FnMut(usize, usize, usize),
+{
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    let expanded = expand_null_arrays(&arrays);
+    let arrays = match &expanded {
+        Some(expanded) => expanded.iter().collect(),
+        None => arrays,
+    };
+    check_data_types(&arrays)?;
+
+    let capacities = concat_capacities(&arrays, 1)?;
+    let mut mutable =
+        MutableArrayData::with_capacities(arrays.clone(), false, capacities);
+    let mut start = 0;
+    for (i, array) in arrays.iter().enumerate() {
+        if array.is_empty() {
+            continue;
+        }
+        mutable.extend(i, 0, array.len());
+        on_extend(i, start, array.len());
+        start += array.len();
+    }
+
+    Ok(make_array(mutable.freeze()))
+}
+
+/// Concatenate multiple [Array] of the same type into a single [ArrayRef],
+/// skipping the first row of an input if it is equal to the last row of the
+/// previous non-empty input.
+///
+/// This is useful when merging sorted runs whose boundaries may overlap. Only
+/// the rows across the seams between inputs are compared, duplicates within an
+/// input are kept. Nulls are considered equal to each other.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array};
+/// use arrow::compute::concat_dedup;
+///
+/// let array = concat_dedup(&[
+///     &Int32Array::from(vec![1, 2, 3]),
+///     &Int32Array::from(vec![3, 4, 5]),
+/// ]).unwrap();
+/// assert_eq!(array.as_ref(), &Int32Array::from(vec![1, 2, 3, 4, 5]) as &dyn Array);
+/// ```
+pub fn concat_dedup(arrays: &[&dyn Array]) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let data = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    let expanded = expand_null_arrays(&data);
+    let data = match &expanded {
+        Some(expanded) => expanded.iter().collect(),
+        None => data,
+    };
+    check_data_types(&data)?;
+
+    let data_type = data[0].data_type();
+
+    let arrays = data
+        .into_iter()
+        .filter(|data| !data.is_empty())
+        .collect::<Vec<_>>();
+    if arrays.is_empty() {
+        return Ok(new_empty_array(data_type));
+    }
+
+    let capacities = concat_capacities(&arrays, 1)?;
+    let mut mutable =
+        MutableArrayData::with_capacities(arrays.clone(), false, capacities);
+
+    mutable.extend(0, 0, arrays[0].len());
+    for (i, pair) in arrays.windows(2).enumerate() {
+        let last = pair[0].slice(pair[0].len() - 1, 1);
+        let start = usize::from(last == pair[1].slice(0, 1));
+        mutable.extend(i + 1, start, pair[1].len())
+    }
+
+    Ok(make_array(mutable.freeze()))
+}
+
+/// Concatenate multiple [ArrayRef] of the same type into a single [ArrayRef].
+///
+/// This behaves like [concat], except that a single input is returned as-is,
+/// by cloning the [ArrayRef] rather than creating a new array over its data.
+///
+/// ```
+/// use arrow::array::{ArrayRef, Int32Array};
+/// use arrow::compute::concat_array_refs;
+/// use std::sync::Arc;
+///
+/// let array: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+/// let result = concat_array_refs(&[array.clone()]).unwrap();
+/// assert!(Arc::ptr_eq(&array, &result));
+/// ```
+pub fn concat_array_refs(arrays: &[ArrayRef]) -> Result<ArrayRef> {
+    match arrays {
+        [array] => Ok(array.clone()),
+        _ => concat(&arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>()),
+    }
+}
+
+/// Concatenate multiple [Array] of the same type into a single [ArrayRef], in
+/// the reverse order of `arrays`.
+///
+/// This is equivalent to calling [concat] on a reversed copy of `arrays`. The
+/// rows of each input keep their order.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array};
+/// use arrow::compute::concat_rev;
+///
+/// let array = concat_rev(&[&Int32Array::from(vec![1, 2]), &Int32Array::from(vec![3])]).unwrap();
+/// assert_eq!(array.as_ref(), &Int32Array::from(vec![3, 1, 2]) as &dyn Array);
+/// ```
+pub fn concat_rev(arrays: &[&dyn Array]) -> Result<ArrayRef> {
+    concat(&arrays.iter().rev().copied().collect::<Vec<_>>())
+}
+
+/// Concatenate the [Some] entries of `arrays`, which must all be of the same
+/// type, into a single [ArrayRef], skipping the [None] entries.
+///
+/// An error is returned if all of `arrays` are [None], as the type of the
+/// result is then unknown.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array};
+/// use arrow::compute::concat_opt;
+///
+/// let a = Int32Array::from(vec![1, 2]);
+/// let b = Int32Array::from(vec![3]);
+/// let array = concat_opt(&[Some(&a), None, Some(&b)]).unwrap();
+/// assert_eq!(array.as_ref(), &Int32Array::from(vec![1, 2, 3]) as &dyn Array);
+/// ```
+pub fn concat_opt(arrays: &[Option<&dyn Array>]) -> Result<ArrayRef> {
+    concat(&arrays.iter().flatten().copied().collect::<Vec<_>>())
+}
+
+/// Concatenate the [Array]s yielded by `arrays`, which must all be of the same
+/// type, into a single [ArrayRef].
+///
+/// This behaves like [concat], without requiring the caller to collect the
+/// arrays into a slice first.
+///
+/// ```
+/// use arrow::array::{Array, ArrayRef, Int32Array};
+/// use arrow::compute::concat_iter;
+/// use std::sync::Arc;
+///
+/// let arrays: Vec<ArrayRef> = vec![
+///     Arc::new(Int32Array::from(vec![1, 2])),
+///     Arc::new(Int32Array::from(vec![3])),
+/// ];
+/// let array = concat_iter(arrays.iter().map(|a| a.as_ref())).unwrap();
+/// assert_eq!(array.len(), 3);
+/// ```
+pub fn concat_iter<'a, I>(arrays: I) -> Result<ArrayRef>
+where
+    I: IntoIterator<Item = &'a dyn Array>,
+{
+    concat(&arrays.into_iter().collect::<Vec<_>>())
+}
+
+/// Concatenate multiple [Array], casting each of them to `to` first, into a
+/// single [ArrayRef].
+///
+/// This allows concatenating arrays whose types are compatible but not
+/// identical, such as [DataType::Int32] and [DataType::Int64]. Inputs are cast
+/// with `safe` set to `false`, so an error is returned if any of them, or any of
+/// their values, cannot be cast to `to`.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array, Int64Array};
+/// use arrow::compute::concat_as;
+/// use arrow::datatypes::DataType;
+///
+/// let array = concat_as(
+///     &[&Int32Array::from(vec![1, 2]), &Int64Array::from(vec![3])],
+///     &DataType::Int64,
+/// ).unwrap();
+/// assert_eq!(array.as_ref(), &Int64Array::from(vec![1, 2, 3]) as &dyn Array);
+/// ```
+pub fn concat_as(arrays: &[&dyn Array], to: &DataType) -> Result<ArrayRef> {
+    let options = CastOptions { safe: false };
+    let arrays = arrays
+        .iter()
+        .map(|array| cast_with_options(&make_array(array.data().clone()), to, &options))
+        .collect::<Result<Vec<_>>>()?;
+
+    concat(&arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>())
+}
+
+/// Concatenate multiple [DecimalArray] into a single [ArrayRef], rescaling
+/// them to `target_scale` first.
+///
+/// The precision of the output is the smallest one that holds the integer
+/// digits of all of `arrays` at `target_scale`. Rescaling to a smaller scale
+/// truncates values towards zero: an error is returned if any value would
+/// lose digits unless `allow_truncation` is `true`. An error is also returned
+/// if any of `arrays` is not a decimal array, or if the output precision would
+/// be greater than [DECIMAL_MAX_PRECISION](crate::datatypes::DECIMAL_MAX_PRECISION).
+///
+/// ```
+/// use arrow::array::{Array, DecimalArray};
+/// use arrow::compute::concat_decimals_rescale;
+/// use arrow::datatypes::DataType;
+///
+/// let a = DecimalArray::from_iter_values([123]).with_precision_and_scale(10, 2).unwrap();
+/// let b = DecimalArray::from_iter_values([45678]).with_precision_and_scale(10, 4).unwrap();
+///
+/// let array = concat_decimals_rescale(&[&a, &b], 4, false).unwrap();
+/// assert_eq!(array.data_type(), &DataType::Decimal(12, 4));
+/// let array = array.as_any().downcast_ref::<DecimalArray>().unwrap();
+/// assert_eq!(array.value_as_string(0), "1.2300");
+/// assert_eq!(array.value_as_string(1), "4.5678");
+/// ```
+pub fn concat_decimals_rescale(
+    arrays: &[&dyn Array],
+    target_scale: usize,
+    allow_truncation: bool,
+) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let arrays = arrays
+        .iter()
+        .map(|array| {
+            array
+                .as_any()
+                .downcast_ref::<DecimalArray>()
+                .ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "concat_decimals_rescale requires decimal arrays, got {:?}",
+                        array.data_type()
+                    ))
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let integer_digits = arrays
+        .iter()
+        .map(|array| array.precision() - array.scale())
+        .max()
+        .unwrap();
+    let precision = integer_digits + target_scale;
+
+    let arrays = arrays
+        .iter()
+        .map(|array| {
+            if array.scale() == target_scale {
+                return DecimalArray::from(array.data().clone())
+                    .with_precision_and_scale(precision, target_scale);
+            }
+            let upscale = array.scale() < target_scale;
+            let factor = 10_i128
+                .checked_pow((array.scale() as i32 - target_scale as i32).unsigned_abs())
+                .ok_or_else(|| {
+                    ArrowError::ComputeError(format!(
+                        "rescaling decimals from scale {} to {} overflows",
+                        array.scale(),
+                        target_scale
+                    ))
+                })?;
+            let rescale = |i: usize| match upscale {
+                true => array.value(i).checked_mul(factor).ok_or_else(|| {
+                    ArrowError::ComputeError(format!(
+                        "rescaling {} to scale {} overflows",
+                        array.value_as_string(i),
+                        target_scale
+                    ))
+                }),
+                false if !allow_truncation && array.value(i) % factor != 0 => {
+                    Err(ArrowError::ComputeError(format!(
+                        "rescaling {} to scale {} would truncate it",
+                        array.value_as_string(i),
+                        target_scale
+                    )))
+                }
+                false => Ok(array.value(i) / factor),
+            };
+            (0..array.len())
+                .map(|i| array.is_valid(i).then(|| rescale(i)).transpose())
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .collect::<DecimalArray>()
+                .with_precision_and_scale(precision, target_scale)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    concat(&arrays.iter().map(|a| a as &dyn Array).collect::<Vec<_>>())
+}
+
+/// Concatenate multiple [ListArray] and [LargeListArray] into a single
+/// [ArrayRef], casting them to a common offset width first.
+///
+/// The output is a [LargeListArray] if any of `arrays` is one, or if the
+/// combined values of `arrays` do not fit in `i32` offsets, and a [ListArray]
+/// otherwise. Only the offsets are unified: an error is returned if any of
+/// `arrays` is not a list, or if their item fields differ.
+///
+/// ```
+/// use arrow::array::{Array, LargeListArray, ListArray};
+/// use arrow::compute::concat_lists_unify_offsets;
+/// use arrow::datatypes::Int32Type;
+///
+/// let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+/// let large_list =
+///     LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(2), Some(3)])]);
+///
+/// let array = concat_lists_unify_offsets(&[&list, &large_list]).unwrap();
+/// let expected = LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1)]),
+///     Some(vec![Some(2), Some(3)]),
+/// ]);
+/// assert_eq!(array.as_ref(), &expected as &dyn Array);
+/// ```
+pub fn concat_lists_unify_offsets(arrays: &[&dyn Array]) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let item_field = |array: &dyn Array| match array.data_type() {
+        DataType::List(field) | DataType::LargeList(field) => Ok(field.clone()),
+        data_type => Err(ArrowError::InvalidArgumentError(format!(
+            "concat_lists_unify_offsets requires list arrays, got {:?}",
+            data_type
+        ))),
+    };
+    let field = item_field(arrays[0])?;
+    for array in arrays {
+        let other = item_field(*array)?;
+        if other != field {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "concat_lists_unify_offsets requires list arrays with the same item field, got {:?} and {:?}",
+                field, other
+            )));
+        }
+    }
+
+    let lists = arrays
+        .iter()
+        .filter(|array| matches!(array.data_type(), DataType::List(_)))
+        .map(|array| array.data())
+        .collect::<Vec<_>>();
+    let values_len = list_values::<i32>(&lists)
+        .iter()
+        .map(|values| values.len())
+        .sum::<usize>();
+    let data_type = match lists.len() == arrays.len() && values_len <= i32::MAX as usize {
+        true => DataType::List(field),
+        false => DataType::LargeList(field),
+    };
+    concat_as(arrays, &data_type)
+}
+
+/// Concatenate multiple [ListArray] and [LargeListArray] into a single
+/// [ArrayRef], widening offsets that would otherwise overflow.
+///
+/// Unlike [concat], which returns an error when the `i32` offsets of a nested
+/// [DataType::Utf8], [DataType::Binary] or [DataType::List] child would
+/// overflow, this promotes that child to its large counterpart. The output is a
+/// [LargeListArray] if any of `arrays` is one, or if the combined values of
+/// `arrays` do not fit in `i32` offsets, and a [ListArray] otherwise. Value
+/// buffers are reused, only offsets are widened. An error is returned if any
+/// of `arrays` is not a list, or if their item fields differ.
+///
+/// ```
+/// use arrow::array::{Array, ListArray};
+/// use arrow::compute::concat_lists_autowide;
+/// use arrow::datatypes::Int32Type;
+///
+/// let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+/// let b = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(2), Some(3)])]);
+///
+/// // nothing overflows, so the output is a ListArray like concat returns
+/// let array = concat_lists_autowide(&[&a, &b]).unwrap();
+/// let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1)]),
+///     Some(vec![Some(2), Some(3)]),
+/// ]);
+/// assert_eq!(array.as_ref(), &expected as &dyn Array);
+/// ```
+pub fn concat_lists_autowide(arrays: &[&dyn Array]) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let item_field = |array: &dyn Array| match array.data_type() {
+        DataType::List(field) | DataType::LargeList(field) => Ok(field.clone()),
+        data_type => Err(ArrowError::InvalidArgumentError(format!(
+            "concat_lists_autowide requires list arrays, got {:?}",
+            data_type
+        ))),
+    };
+    let field = item_field(arrays[0])?;
+    for array in arrays {
+        let other = item_field(*array)?;
+        if other != field {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "concat_lists_autowide requires list arrays with the same item field, got {:?} and {:?}",
+                field, other
+            )));
+        }
+    }
+
+    let data = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    let data_type = autowide_type(arrays[0].data_type(), &data);
+    let arrays = data
+        .iter()
+        .map(|data| autowide_data(data, &data_type).map(make_array))
+        .collect::<Result<Vec<_>>>()?;
+    concat(&arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>())
+}
+
+/// Returns the [DataType] that `arrays`, which must all have `data_type` up to
+/// the offset width of lists, can be concatenated as without any `i32` offsets
+/// overflowing
+fn autowide_type(data_type: &DataType, arrays: &[&ArrayData]) -> DataType {
+    match data_type {
+        DataType::Utf8
+            if compute_binary_values_length::<i32>(arrays) > i32::MAX as usize =>
+        {
+            DataType::LargeUtf8
+        }
+        DataType::Binary
+            if compute_binary_values_length::<i32>(arrays) > i32::MAX as usize =>
+        {
+            DataType::LargeBinary
+        }
+        DataType::List(field) | DataType::LargeList(field) => {
+            let values = arrays
+                .iter()
+                .flat_map(|data| match data.data_type() {
+                    DataType::List(_) => list_values::<i32>(&[data]),
+                    _ => list_values::<i64>(&[data]),
+                })
+                .collect::<Vec<_>>();
+            let item_type =
+                autowide_type(field.data_type(), &values.iter().collect::<Vec<_>>());
+            let field = Field::new_dict(
+                field.name(),
+                item_type,
+                field.is_nullable(),
+                field.dict_id().unwrap_or_default(),
+                field.dict_is_ordered().unwrap_or_default(),
+            )
+            .with_metadata(field.metadata().cloned());
+            let large = arrays
+                .iter()
+                .any(|data| matches!(data.data_type(), DataType::LargeList(_)))
+                || values.iter().map(|v| v.len()).sum::<usize>() > i32::MAX as usize;
+            match large {
+                true => DataType::LargeList(Box::new(field)),
+                false => DataType::List(Box::new(field)),
+            }
+        }
+        data_type => data_type.clone(),
+    }
+}
+
+/// Returns `data` cast to `data_type`, which must only differ from the data
+/// type of `data` by the offset width of it and its nested children
+fn autowide_data(data: &ArrayData, data_type: &DataType) -> Result<ArrayData> {
+    match (data.data_type(), data_type) {
+        (from, to) if from == to => Ok(data.clone()),
+        (
+            DataType::List(_) | DataType::LargeList(_),
+            DataType::List(field) | DataType::LargeList(field),
+        ) => {
+            // widen the child first, as the offsets of a list can only be cast
+            // when the children of both list types are the same
+            let child = autowide_data(&data.child_data()[0], field.data_type())?;
+            let list_type = match data.data_type() {
+                DataType::List(_) => DataType::List(field.clone()),
+                _ => DataType::LargeList(field.clone()),
+            };
+            let builder = ArrayData::builder(list_type)
+                .len(data.len())
+                .offset(data.offset())
+                .null_bit_buffer(data.null_buffer().cloned())
+                .buffers(data.buffers().to_vec())
+                .child_data(vec![child]);
+            // Safety: only the type of the child differs, and it has the same length
+            let list = make_array(unsafe { builder.build_unchecked() });
+            Ok(cast(&list, data_type)?.data().clone())
+        }
+        _ => Ok(cast(&make_array(data.clone()), data_type)?.data().clone()),
+    }
+}
+
+/// Concatenate multiple [LargeStringArray] into a single [ArrayRef], using the
+/// smaller [DataType::Utf8] offsets when possible.
+///
+/// The output type is data dependent: if the combined values of `arrays` fit in
+/// `i32` offsets a [StringArray] is returned, otherwise a [LargeStringArray].
+/// An error is returned if any of `arrays` is not a [DataType::LargeUtf8] array.
+///
+/// ```
+/// use arrow::array::{Array, LargeStringArray, StringArray};
+/// use arrow::compute::concat_compact_utf8;
+///
+/// let array = concat_compact_utf8(&[
+///     &LargeStringArray::from(vec!["hello", "world"]),
+///     &LargeStringArray::from(vec!["!"]),
+/// ]).unwrap();
+/// assert_eq!(array.as_ref(), &StringArray::from(vec!["hello", "world", "!"]) as &dyn Array);
+/// ```
+pub fn concat_compact_utf8(arrays: &[&dyn Array]) -> Result<ArrayRef> {
+    if let Some(array) = arrays
+        .iter()
+        .find(|array| array.data_type() != &DataType::LargeUtf8)
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "concat_compact_utf8 requires LargeUtf8 arrays, got {:?}",
+            array.data_type()
+        )));
+    }
+
+    let data = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    if compute_binary_values_length::<i64>(&data) <= i32::MAX as usize {
+        concat_as(arrays, &DataType::Utf8)
+    } else {
+        concat(arrays)
+    }
+}
+
+/// Concatenate multiple [DictionaryArray] with the same value type but possibly
+/// different key types into a single [ArrayRef].
+///
+/// The keys of every input are first cast to the key type of `arrays` that can
+/// index the most values, and the dictionaries are then concatenated like
+/// [concat] does. An error is returned if any of `arrays` is not a dictionary,
+/// if their value types differ, or if the merged values cannot be indexed by
+/// the widest key type.
+///
+/// If all of `arrays` share the same values, only their keys are widened and
+/// the values are reused without being copied.
+///
+/// ```
+/// use arrow::array::{Array, DictionaryArray};
+/// use arrow::compute::concat_dictionaries_promote_keys;
+/// use arrow::datatypes::{DataType, Int16Type, Int8Type};
+///
+/// let a: DictionaryArray<Int8Type> = vec!["a", "b"].into_iter().collect();
+/// let b: DictionaryArray<Int16Type> = vec!["c"].into_iter().collect();
+/// let array = concat_dictionaries_promote_keys(&[&a, &b]).unwrap();
+/// assert_eq!(
+///     array.data_type(),
+///     &DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8))
+/// );
+/// ```
+pub fn concat_dictionaries_promote_keys(arrays: &[&dyn Array]) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let (mut key_type, value_type) = match arrays[0].data_type() {
+        DataType::Dictionary(key_type, value_type) => (key_type, value_type),
+        data_type => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "concat_dictionaries_promote_keys requires dictionary arrays, got {:?}",
+                data_type
+            )))
+        }
+    };
+    for array in arrays {
+        match array.data_type() {
+            DataType::Dictionary(k, v) if v == value_type => {
+                if dictionary_key_max(k) > dictionary_key_max(key_type) {
+                    key_type = k;
+                }
+            }
+            data_type => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "concat_dictionaries_promote_keys requires dictionary arrays with the same value type, got {:?} and {:?}",
+                    arrays[0].data_type(),
+                    data_type
+                )))
+            }
+        }
+    }
+
+    let data_type = DataType::Dictionary(key_type.clone(), value_type.clone());
+    let values = &arrays[0].data().child_data()[0];
+    if !arrays
+        .iter()
+        .all(|array| array.data().child_data()[0].ptr_eq(values))
+    {
+        return concat_as(arrays, &data_type);
+    }
+
+    // the values are shared, so only the keys need to be widened
+    let options = CastOptions { safe: false };
+    let keys = arrays
+        .iter()
+        .map(|array| {
+            let data = array.data();
+            let array_key_type = match data.data_type() {
+                DataType::Dictionary(key_type, _) => key_type.as_ref(),
+                _ => unreachable!(),
+            };
+            let keys = ArrayData::builder(array_key_type.clone())
+                .len(data.len())
+                .offset(data.offset())
+                .null_bit_buffer(data.null_buffer().cloned())
+                .add_buffer(data.buffers()[0].clone())
+                .build()?;
+            cast_with_options(&make_array(keys), key_type, &options)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let keys = concat(&keys.iter().map(|k| k.as_ref()).collect::<Vec<_>>())?;
+
+    let data = ArrayData::builder(data_type)
+        .len(keys.len())
+        .offset(keys.offset())
+        .null_bit_buffer(keys.data().null_buffer().cloned())
+        .add_buffer(keys.data().buffers()[0].clone())
+        .add_child_data(values.clone())
+        .build()?;
+    Ok(make_array(data))
+}
+
+/// Returns the largest key of the dictionary key type `key_type`
+fn dictionary_key_max(key_type: &DataType) -> u64 {
+    match key_type {
+        DataType::Int8 => i8::MAX as u64,
+        DataType::UInt8 => u8::MAX as u64,
+        DataType::Int16 => i16::MAX as u64,
+        DataType::UInt16 => u16::MAX as u64,
+        DataType::Int32 => i32::MAX as u64,
+        DataType::UInt32 => u32::MAX as u64,
+        DataType::Int64 => i64::MAX as u64,
+        _ => u64::MAX,
+    }
+}
+
+/// Concatenate multiple [StructArray] whose fields may be in a different order
+/// into a single [ArrayRef].
+///
+/// Fields are matched by name, and the result has the fields in the order of
+/// the first of `arrays`. An error is returned if any of `arrays` is not a
+/// struct array, does not have the same set of field names as the first one,
+/// or if a field has a different [DataType] than the field of the same name in
+/// the first one.
+///
+/// ```
+/// use arrow::array::{Array, ArrayRef, Int32Array, StringArray, StructArray};
+/// use arrow::compute::concat_structs_reorder;
+/// use arrow::datatypes::{DataType, Field};
+/// use std::sync::Arc;
+///
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+/// let b: ArrayRef = Arc::new(StringArray::from(vec!["b"]));
+/// let ab = StructArray::from(vec![
+///     (Field::new("a", DataType::Int32, false), a.clone()),
+///     (Field::new("b", DataType::Utf8, false), b.clone()),
+/// ]);
+/// let ba = StructArray::from(vec![
+///     (Field::new("b", DataType::Utf8, false), b),
+///     (Field::new("a", DataType::Int32, false), a),
+/// ]);
+///
+/// let array = concat_structs_reorder(&[&ab, &ba]).unwrap();
+/// assert_eq!(array.data_type(), ab.data_type());
+/// assert_eq!(array.len(), 2);
+/// ```
+pub fn concat_structs_reorder(arrays: &[&dyn Array]) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let struct_fields = |array: &dyn Array| match array.data_type() {
+        DataType::Struct(fields) => Ok(fields.clone()),
+        data_type => Err(ArrowError::InvalidArgumentError(format!(
+            "concat_structs_reorder requires struct arrays, got {:?}",
+            data_type
+        ))),
+    };
+    let fields = struct_fields(arrays[0])?;
+
+    let arrays = arrays
+        .iter()
+        .map(|array| {
+            let array_fields = struct_fields(*array)?;
+            if array_fields.len() != fields.len() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "It is not possible to concatenate struct arrays with different fields: {:?} and {:?}",
+                    fields, array_fields
+                )));
+            }
+
+            let data = array.data();
+            let child_data = fields
+                .iter()
+                .map(|field| {
+                    let (i, array_field) = array_fields
+                        .iter()
+                        .enumerate()
+                        .find(|(_, f)| f.name() == field.name())
+                        .ok_or_else(|| {
+                            ArrowError::InvalidArgumentError(format!(
+                                "It is not possible to concatenate struct arrays with different fields: field {} is missing from {:?}",
+                                field.name(),
+                                array_fields
+                            ))
+                        })?;
+                    if array_field.data_type() != field.data_type() {
+                        return Err(ArrowError::InvalidArgumentError(format!(
+                            "It is not possible to concatenate struct arrays with different fields: field {} is {:?} and {:?}",
+                            field.name(),
+                            field.data_type(),
+                            array_field.data_type()
+                        )));
+                    }
+                    Ok(data.child_data()[i].clone())
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let builder = ArrayData::builder(DataType::Struct(fields.clone()))
+                .len(data.len())
+                .offset(data.offset())
+                .null_bit_buffer(data.null_buffer().cloned())
+                .child_data(child_data);
+            // Safety: only the order of the fields and children changed
+            Ok(unsafe { builder.build_unchecked() })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(make_array(concat_data(&arrays.iter().collect::<Vec<_>>())?))
+}
+
+/// Concatenate multiple [PrimitiveArray] of the same type into a single
+/// [PrimitiveArray], without the need to downcast the result.
+///
+/// ```
+/// use arrow::array::Int32Array;
+/// use arrow::compute::concat_primitive;
+///
+/// let array = concat_primitive(&[
+///     &Int32Array::from(vec![1, 2]),
+///     &Int32Array::from(vec![Some(3), None]),
+/// ]).unwrap();
+/// assert_eq!(array.value(2), 3);
+/// ```
+pub fn concat_primitive<T: ArrowPrimitiveType>(
+    arrays: &[&PrimitiveArray<T>],
+) -> Result<PrimitiveArray<T>> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    } else if arrays.len() == 1 {
+        return Ok(arrays[0].data().clone().into());
+    }
+
+    // the data types of arrays of the same native type may still differ, for
+    // instance by the timezone of timestamps or the precision of decimals
+    let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    check_data_types(&arrays)?;
+
+    Ok(concat_data(&arrays)?.into())
+}
+
+/// Appends a single row holding `scalar`, or a null row if `scalar` is `None`,
+/// to `array`, returning the result as an [ArrayRef].
+///
+/// This behaves like [concat] with a single row array holding `scalar`,
+/// without creating that array. The result has the [DataType] of `array`,
+/// which must be a [PrimitiveArray] of `T`, otherwise an error is returned.
+///
+/// ```
+/// use arrow::array::{Array, Int64Array};
+/// use arrow::compute::concat_with_scalar;
+/// use arrow::datatypes::Int64Type;
+///
+/// let array = Int64Array::from(vec![1, 2]);
+/// let result = concat_with_scalar::<Int64Type>(&array, Some(3)).unwrap();
+/// assert_eq!(result.as_ref(), &Int64Array::from(vec![1, 2, 3]) as &dyn Array);
+///
+/// let result = concat_with_scalar::<Int64Type>(&array, None).unwrap();
+/// assert_eq!(result.as_ref(), &Int64Array::from(vec![Some(1), Some(2), None]) as &dyn Array);
+/// ```
+pub fn concat_with_scalar<T: ArrowPrimitiveType>(
+    array: &dyn Array,
+    scalar: Option<T::Native>,
+) -> Result<ArrayRef> {
+    let primitive = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "concat_with_scalar expects an array of type {:?}, got {:?}",
+                T::DATA_TYPE,
+                array.data_type()
+            ))
+        })?;
+
+    let len = array.len() + 1;
+    let mut values = MutableBuffer::new(len * std::mem::size_of::<T::Native>());
+    values.extend_from_slice(primitive.values());
+    values.push(scalar.unwrap_or_default());
+
+    let nulls = match (array.data().null_buffer(), scalar.is_some()) {
+        (None, true) => None,
+        (bitmap, _) => {
+            let mut nulls = MutableBuffer::new_null(len);
+            match bitmap {
+                Some(bitmap) => {
+                    set_bits(
+                        nulls.as_slice_mut(),
+                        bitmap.as_slice(),
+                        0,
+                        array.offset(),
+                        array.len(),
+                    );
+                }
+                None => (0..array.len())
+                    .for_each(|i| bit_util::set_bit(nulls.as_slice_mut(), i)),
+            }
+            if scalar.is_some() {
+                bit_util::set_bit(nulls.as_slice_mut(), array.len());
+            }
+            Some(nulls.into())
+        }
+    };
+
+    let builder = ArrayData::builder(array.data_type().clone())
+        .len(len)
+        .null_bit_buffer(nulls)
+        .add_buffer(values.into());
+    // Safety: the values and null buffers are sized from the length of the result
+    Ok(make_array(unsafe { builder.build_unchecked() }))
+}
+
+/// Returns an error if `arrays` do not all have the same [DataType]
+fn check_data_types(arrays: &[&ArrayData]) -> Result<()> {
+    let data_type = arrays[0].data_type();
+    match arrays
+        .iter()
+        .position(|array| array.data_type() != data_type)
+    {
+        None => check_supported(data_type),
+        Some(i) => match (data_type, arrays[i].data_type()) {
+            (DataType::Decimal(p1, s1), DataType::Decimal(p2, s2)) => {
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "It is not possible to concatenate decimal arrays with different precision or scale: Decimal({}, {}) and Decimal({}, {})",
+                    p1, s1, p2, s2
+                )))
+            }
+            (DataType::Map(f1, sorted1), DataType::Map(f2, sorted2)) => {
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "It is not possible to concatenate map arrays with different entries: {:?} (keys sorted: {}) and {:?} (keys sorted: {})",
+                    f1, sorted1, f2, sorted2
+                )))
+            }
+            (DataType::Union(f1, ids1, mode1), DataType::Union(f2, ids2, mode2)) => {
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "It is not possible to concatenate union arrays with different fields, type ids or modes: {:?} {:?} {:?} and {:?} {:?} {:?}",
+                    f1, ids1, mode1, f2, ids2, mode2
+                )))
+            }
+            (DataType::Interval(u1), DataType::Interval(u2)) => {
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "It is not possible to concatenate interval arrays with different units: {:?} and {:?}",
+                    u1, u2
+                )))
+            }
+            (DataType::Timestamp(_, _), DataType::Timestamp(_, _))
+            | (DataType::Time32(_), DataType::Time32(_))
+            | (DataType::Time64(_), DataType::Time64(_))
+            | (DataType::Duration(_), DataType::Duration(_)) => {
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "It is not possible to concatenate temporal arrays with different units or timezones: {:?} and {:?}",
+                    data_type,
+                    arrays[i].data_type()
+                )))
+            }
+            (DataType::List(f1), DataType::List(f2))
+            | (DataType::LargeList(f1), DataType::LargeList(f2))
+            | (DataType::FixedSizeList(f1, _), DataType::FixedSizeList(f2, _))
+                if f1 != f2 =>
+            {
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "It is not possible to concatenate list arrays with different item fields: {:?} and {:?}",
+                    f1, f2
+                )))
+            }
+            (DataType::FixedSizeList(_, s1), DataType::FixedSizeList(_, s2)) => {
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "It is not possible to concatenate fixed size list arrays with different sizes: {} and {}",
+                    s1, s2
+                )))
+            }
+            (DataType::Dictionary(k1, v1), DataType::Dictionary(k2, v2)) if v1 == v2 => {
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "It is not possible to concatenate dictionary arrays with different key types: {:?} and {:?}, consider concat_dictionaries_promote_keys",
+                    k1, k2
+                )))
+            }
+            (DataType::Struct(f1), DataType::Struct(f2)) if f1.len() == f2.len() => {
+                let (f1, f2) = f1.iter().zip(f2).find(|(f1, f2)| f1 != f2).unwrap();
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "It is not possible to concatenate struct arrays with different fields: {:?} and {:?}",
+                    f1, f2
+                )))
+            }
+            (_, other) => Err(ArrowError::InvalidArgumentError(format!(
+                "It is not possible to concatenate arrays of different data types: array 0 has type {:?} but array {} has type {:?}",
+                data_type, i, other
+            ))),
+        },
+    }
+}
+
+/// Returns an error if arrays of `data_type`, or any of its nested types,
+/// cannot be concatenated, instead of panicking while concatenating them.
+fn check_supported(data_type: &DataType) -> Result<()> {
+    match data_type {
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => check_supported(field.data_type()),
+        DataType::Struct(fields) => fields
+            .iter()
+            .try_for_each(|field| check_supported(field.data_type())),
+        DataType::Union(_, type_ids, UnionMode::Dense)
+            if type_ids.iter().enumerate().any(|(i, id)| *id as usize != i) =>
+        {
+            // the type ids of dense unions are used to index their children
+            Err(ArrowError::NotYetImplemented(format!(
+                "concat of dense union arrays with type ids {:?} that are not the indices of their fields",
+                type_ids
+            )))
+        }
+        DataType::Union(fields, _, _) => fields
+            .iter()
+            .try_for_each(|field| check_supported(field.data_type())),
+        DataType::Dictionary(_, value_type) => check_supported(value_type),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the total length of `arrays`, `repeat` times over, or an error if it
+/// overflows `usize`, which is possible on 32-bit targets.
+fn concat_len(arrays: &[&ArrayData], repeat: usize) -> Result<usize> {
+    arrays
+        .iter()
+        .try_fold(0usize, |len, array| len.checked_add(array.len()))
+        .and_then(|len| len.checked_mul(repeat))
+        .ok_or_else(|| concat_overflow_error(arrays, repeat))
+}
+
+/// Returns the error of a concat of `arrays`, `repeat` times over, whose size
+/// overflows `usize`
+fn concat_overflow_error(arrays: &[&ArrayData], repeat: usize) -> ArrowError {
+    ArrowError::ComputeError(format!(
+        "concat of {} arrays repeated {} times would overflow usize length",
+        arrays.len(),
+        repeat
+    ))
+}
+
+/// Returns the [Capacities] needed to hold the values of all `arrays`,
+/// `repeat` times over.
+fn concat_capacities(arrays: &[&ArrayData], repeat: usize) -> Result<Capacities> {
+    let capacity = concat_len(arrays, repeat)?;
+
+    Ok(match arrays[0].data_type() {
+        DataType::Utf8 | DataType::Binary => {
+            let values_size =
+                compute_binary_values_length::<i32>(arrays).saturating_mul(repeat);
+            if values_size > i32::MAX as usize {
+                let large_type = match arrays[0].data_type() {
+                    DataType::Utf8 => DataType::LargeUtf8,
+                    _ => DataType::LargeBinary,
+                };
+                return Err(ArrowError::ComputeError(format!(
+                    "concat of {:?} arrays with {} value bytes would overflow i32 offsets, consider casting to {:?} first",
+                    arrays[0].data_type(),
+                    values_size,
+                    large_type
+                )));
+            }
+            Capacities::Binary(capacity, Some(values_size))
+        }
+        DataType::LargeUtf8 | DataType::LargeBinary => {
+            let values_size = compute_binary_values_length::<i64>(arrays)
+                .checked_mul(repeat)
+                .ok_or_else(|| concat_overflow_error(arrays, repeat))?;
+            Capacities::Binary(capacity, Some(values_size))
+        }
+        DataType::List(_) | DataType::Map(_, _) => {
+            let values = list_values::<i32>(arrays);
+            let values_capacities = child_capacities(&values, repeat)?;
+            Capacities::List(capacity, Some(Box::new(values_capacities)))
+        }
+        DataType::LargeList(_) => {
+            let values = list_values::<i64>(arrays);
+            let values_capacities = child_capacities(&values, repeat)?;
+            Capacities::List(capacity, Some(Box::new(values_capacities)))
+        }
+        DataType::FixedSizeList(_, size) => {
+            let size = *size as usize;
+            let values = arrays
+                .iter()
+                .map(|array| {
+                    array.child_data()[0].slice(array.offset() * size, array.len() * size)
+                })
+                .collect::<Vec<_>>();
+            let values_capacities = child_capacities(&values, repeat)?;
+            Capacities::List(capacity, Some(Box::new(values_capacities)))
+        }
+        DataType::Struct(fields) => {
+            // the children of a struct are indexed like the struct itself
+            let children_capacities = (0..fields.len())
+                .map(|i| {
+                    let children = arrays
+                        .iter()
+                        .map(|array| array.child_data()[i].slice(0, array.len()))
+                        .collect::<Vec<_>>();
+                    child_capacities(&children, repeat)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Capacities::Struct(capacity, Some(children_capacities))
+        }
+        DataType::Dictionary(key_type, _)
+            if !arrays
+                .windows(2)
+                .all(|a| a[0].child_data()[0].ptr_eq(&a[1].child_data()[0])) =>
+        {
+            // the dictionary values are only concatenated once, even when repeated
+            let values = arrays
+                .iter()
+                .map(|array| &array.child_data()[0])
+                .collect::<Vec<_>>();
+            let values_len = values.iter().map(|v| v.len()).sum::<usize>();
+            if values_len as u64 > dictionary_key_max(key_type) {
+                return Err(ArrowError::DictionaryKeyOverflowError);
+            }
+            let values_capacities = concat_capacities(&values, 1)?;
+            Capacities::Dictionary(capacity, Some(Box::new(values_capacities)))
+        }
+        _ => Capacities::Array(capacity),
+    })
+}
+
+/// Returns the [Capacities] of the child data `children` of nested arrays,
+/// `repeat` times over
+fn child_capacities(children: &[ArrayData], repeat: usize) -> Result<Capacities> {
+    match children.is_empty() {
+        true => Ok(Capacities::Array(0)),
+        false => concat_capacities(&children.iter().collect::<Vec<_>>(), repeat),
+    }
+}
+
+/// Concatenates `arrays`, which must all have the same [DataType], into a
+/// single [ArrayData], preallocating the value buffers where possible.
+///
+/// Empty arrays are skipped. If all of `arrays` are empty, the first one is
+/// returned, and if only one of them is not empty, it is returned without copying.
+fn concat_data(arrays: &[&ArrayData]) -> Result<ArrayData> {
+    let non_empty = arrays
+        .iter()
+        .filter(|array| !array.is_empty())
+        .copied()
+        .collect::<Vec<_>>();
+    match non_empty.len() {
+        0 => return Ok(arrays[0].clone()),
+        1 => return Ok(non_empty[0].clone()),
+        _ => {}
+    }
+    let arrays = non_empty;
+    // the fast paths below sum the lengths of `arrays` unchecked
+    concat_len(&arrays, 1)?;
+
+    if arrays.windows(2).all(|a| a[0].ptr_eq(a[1])) {
+        return concat_repeated(arrays[0], arrays.len());
+    }
+
+    if let Some(data) = concat_adjacent_slices(&arrays) {
+        return Ok(data);
+    }
+
+    if arrays[0].data_type() == &DataType::Boolean {
+        return Ok(concat_boolean(&arrays));
+    }
+
+    if let Some(byte_width) = fixed_byte_width(arrays[0].data_type()) {
+        let small = arrays.iter().all(|array| array.len() <= SMALL_ARRAY_LEN);
+        let null_count = arrays.iter().map(|array| array.null_count()).sum::<usize>();
+        let len = arrays.iter().map(|array| array.len()).sum::<usize>();
+        if small || null_count * 100 >= len * SPARSE_NULL_PERCENT {
+            return Ok(concat_fixed_width(&arrays, byte_width));
+        }
+        #[cfg(feature = "rayon")]
+        if arrays.len() >= PARALLEL_MIN_ARRAYS && len * byte_width >= PARALLEL_MIN_BYTES {
+            return Ok(concat_fixed_width_parallel(&arrays, byte_width));
+        }
+    }
+
+    let capacities = concat_capacities(&arrays, 1)?;
+    // MutableArrayData only builds a null buffer if any of `arrays` has nulls
+    let mut mutable =
+        MutableArrayData::with_capacities(arrays.clone(), false, capacities);
+
+    for (i, array) in arrays.iter().enumerate() {
+        mutable.extend(i, 0, array.len())
+    }
+
+    Ok(mutable.freeze())
+}
+
+/// Returns a view over the values of `arrays` if they are adjacent slices of
+/// the same values buffer, without nulls, of a fixed width type.
+///
+/// This is the case when rejoining the slices of a split array, which can then
+/// be done without copying.
+fn concat_adjacent_slices(arrays: &[&ArrayData]) -> Option<ArrayData> {
+    fixed_byte_width(arrays[0].data_type())?;
+
+    let values = arrays[0].buffers()[0].as_ptr();
+    let adjacent = arrays
+        .iter()
+        .all(|array| array.null_count() == 0 && array.buffers()[0].as_ptr() == values)
+        && arrays
+            .windows(2)
+            .all(|a| a[0].offset() + a[0].len() == a[1].offset());
+    if !adjacent {
+        return None;
+    }
+
+    let len = arrays.iter().map(|array| array.len()).sum();
+    // the values buffer of the last slice extends at least to the end of the view
+    let builder = ArrayData::builder(arrays[0].data_type().clone())
+        .len(len)
+        .offset(arrays[0].offset())
+        .buffers(arrays[arrays.len() - 1].buffers().to_vec());
+    // Safety: every slice is valid, and together they cover the view
+    Some(unsafe { builder.build_unchecked() })
+}
+
+/// Returns the width in bytes of the values of `data_type`, if it is a fixed
+/// width type without child data
+fn fixed_byte_width(data_type: &DataType) -> Option<usize> {
+    use crate::datatypes::IntervalUnit;
+    use std::mem::size_of;
+
+    match data_type {
+        DataType::Int8 | DataType::UInt8 => Some(size_of::<i8>()),
+        DataType::Int16 | DataType::UInt16 | DataType::Float16 => Some(size_of::<i16>()),
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Float32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => Some(size_of::<i32>()),
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime) => Some(size_of::<i64>()),
+        DataType::Decimal(_, _) | DataType::Interval(IntervalUnit::MonthDayNano) => {
+            Some(size_of::<i128>())
+        }
+        DataType::FixedSizeBinary(size) => Some(*size as usize),
+        _ => None,
+    }
+}
+
+/// The length up to which inputs of a fixed width type are concatenated by
+/// [concat_fixed_width]
+const SMALL_ARRAY_LEN: usize = 16;
+
+/// The percentage of null slots from which inputs of a fixed width type are
+/// concatenated by [concat_fixed_width]
+const SPARSE_NULL_PERCENT: usize = 90;
+
+/// The number of inputs of a fixed width type from which, with the `rayon`
+/// feature, they are concatenated by `concat_fixed_width_parallel`
+#[cfg(feature = "rayon")]
+const PARALLEL_MIN_ARRAYS: usize = 4;
+
+/// The total size in bytes of the values of inputs of a fixed width type from
+/// which, with the `rayon` feature, they are concatenated by
+/// `concat_fixed_width_parallel`. Below this, the cost of dispatching the
+/// copies to the thread pool outweighs copying them serially.
+#[cfg(feature = "rayon")]
+const PARALLEL_MIN_BYTES: usize = 1 << 20;
+
+/// Concatenates `arrays` of a fixed width type of `byte_width` bytes by
+/// appending their values to a single buffer.
+///
+/// For many small inputs, this avoids the per input bookkeeping of
+/// [MutableArrayData], which otherwise dominates the cost of copying. For
+/// mostly null inputs, this copies their null bitmaps with bit operations
+/// rather than slot by slot. The values of null slots are copied along with
+/// the others, as one copy per input is cheaper than skipping them.
+fn concat_fixed_width(arrays: &[&ArrayData], byte_width: usize) -> ArrayData {
+    let len = arrays.iter().map(|array| array.len()).sum::<usize>();
+
+    let mut values = MutableBuffer::new(len * byte_width);
+    for array in arrays {
+        let start = array.offset() * byte_width;
+        values.extend_from_slice(
+            &array.buffers()[0][start..start + array.len() * byte_width],
+        );
+    }
+
+    let nulls = concat_null_buffers(arrays, len);
+
+    let builder = ArrayData::builder(arrays[0].data_type().clone())
+        .len(len)
+        .null_bit_buffer(nulls)
+        .add_buffer(values.into());
+    // Safety: the values and null buffers are sized from the lengths of the
+    // inputs, which all share the same fixed width data type
+    unsafe { builder.build_unchecked() }
+}
+
+/// Concatenates `arrays` of a fixed width type of `byte_width` bytes, copying
+/// the values of each input into its own region of the output in parallel.
+///
+/// The destination of each input is fully determined by the lengths of the
+/// inputs before it, so the copies are independent of each other.
+///
+/// This is only used for at least [PARALLEL_MIN_ARRAYS] inputs with at least
+/// [PARALLEL_MIN_BYTES] bytes of values.
+#[cfg(feature = "rayon")]
+fn concat_fixed_width_parallel(arrays: &[&ArrayData], byte_width: usize) -> ArrayData {
+    use rayon::prelude::*;
+
+    let len = arrays.iter().map(|array| array.len()).sum::<usize>();
+
+    let mut values = MutableBuffer::new(len * byte_width);
+    let mut regions = Vec::with_capacity(arrays.len());
+    let mut offset = 0;
+    for array in arrays {
+        regions.push((offset, *array));
+        offset += array.len() * byte_width;
+    }
+    // raw pointers are not Send, so the address of the output is shared instead
+    let output = values.as_mut_ptr() as usize;
+    regions.into_par_iter().for_each(|(offset, array)| {
+        let start = array.offset() * byte_width;
+        let region = &array.buffers()[0][start..start + array.len() * byte_width];
+        // Safety: the regions are disjoint and within the capacity of `values`
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                region.as_ptr(),
+                (output as *mut u8).add(offset),
+                region.len(),
+            )
+        };
+    });
+    // Safety: the regions of all inputs cover `len * byte_width` bytes
+    unsafe { values.set_len(len * byte_width) };
+
+    let nulls = concat_null_buffers(arrays, len);
+
+    let builder = ArrayData::builder(arrays[0].data_type().clone())
+        .len(len)
+        .null_bit_buffer(nulls)
+        .add_buffer(values.into());
+    // Safety: the values and null buffers are sized from the lengths of the
+    // inputs, which all share the same fixed width data type
+    unsafe { builder.build_unchecked() }
+}
+
+/// Concatenates boolean `arrays` by copying the bits of their values into a
+/// single buffer, 64 bits at a time whatever the alignment of their offsets.
+fn concat_boolean(arrays: &[&ArrayData]) -> ArrayData {
+    let len = arrays.iter().map(|array| array.len()).sum::<usize>();
+
+    let mut values = MutableBuffer::new_null(len);
+    let mut offset = 0;
+    for array in arrays {
+        set_bits(
+            values.as_slice_mut(),
+            array.buffers()[0].as_slice(),
+            offset,
+            array.offset(),
+            array.len(),
+        );
+        offset += array.len();
+    }
+
+    let nulls = concat_null_buffers(arrays, len);
+
+    let builder = ArrayData::builder(DataType::Boolean)
+        .len(len)
+        .null_bit_buffer(nulls)
+        .add_buffer(values.into());
+    // Safety: the values and null buffers are sized from the lengths of the
+    // inputs, which are all boolean arrays
+    unsafe { builder.build_unchecked() }
+}
+
+/// Returns the validity bitmap of the concatenation of `arrays`, whose lengths
+/// add up to `len`, or `None` if none of them contain nulls
+fn concat_null_buffers(arrays: &[&ArrayData], len: usize) -> Option<Buffer> {
+    if arrays.iter().all(|array| array.null_count() == 0) {
+        return None;
+    }
+
+    let mut nulls = MutableBuffer::new_null(len);
+    let mut offset = 0;
+    for array in arrays {
+        match array.null_buffer() {
+            Some(bitmap) => {
+                set_bits(
+                    nulls.as_slice_mut(),
+                    bitmap.as_slice(),
+                    offset,
+                    array.offset(),
+                    array.len(),
+                );
+            }
+            None => (offset..offset + array.len())
+                .for_each(|i| bit_util::set_bit(nulls.as_slice_mut(), i)),
+        }
+        offset += array.len();
+    }
+    Some(nulls.into())
+}
+
+/// Appends the values of `arrays` to `values`, returning the validity bitmap of
+/// the appended values, or `None` if none of `arrays` contain nulls.
+///
+/// `values` is grown at most once, so no allocation happens if it already has
+/// enough spare capacity. Together with the returned bitmap, it can be used to
+/// build a [PrimitiveArray] equal to the [concat] of `arrays`.
+///
+/// ```
+/// use arrow::array::{ArrayData, Int32Array};
+/// use arrow::buffer::MutableBuffer;
+/// use arrow::compute::concat_into;
+/// use arrow::datatypes::DataType;
+///
+/// let mut values = MutableBuffer::new(64);
+/// let nulls = concat_into(
+///     &[&Int32Array::from(vec![1, 2]), &Int32Array::from(vec![Some(3), None])],
+///     &mut values,
+/// ).unwrap();
+/// let data = ArrayData::builder(DataType::Int32)
+///     .len(4)
+///     .add_buffer(values.into())
+///     .null_bit_buffer(nulls)
+///     .build()
+///     .unwrap();
+/// assert_eq!(Int32Array::from(data), Int32Array::from(vec![Some(1), Some(2), Some(3), None]));
+/// ```
+pub fn concat_into<T: ArrowPrimitiveType>(
+    arrays: &[&PrimitiveArray<T>],
+    values: &mut MutableBuffer,
+) -> Result<Option<Buffer>> {
+    let len = arrays.iter().map(|array| array.len()).sum::<usize>();
+    values.reserve(len * std::mem::size_of::<T::Native>());
+    for array in arrays {
+        values.extend_from_slice(array.values());
+    }
+
+    let data = arrays.iter().map(|array| array.data()).collect::<Vec<_>>();
+    Ok(concat_null_buffers(&data, len))
+}
+
+/// Concatenates `arrays`, which must all be of the same type, into a [Vec] of
+/// arrays of `chunk_size` rows, the last of which may be shorter.
+///
+/// This is equivalent to slicing the result of [concat] into chunks, without
+/// copying the rows into a single array first. If `arrays` contain no rows, an
+/// empty [Vec] is returned.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array};
+/// use arrow::compute::concat_chunked;
+///
+/// let chunks = concat_chunked(
+///     &[&Int32Array::from(vec![1, 2, 3, 4, 5]), &Int32Array::from(vec![6, 7])],
+///     3,
+/// ).unwrap();
+/// let lengths = chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>();
+/// assert_eq!(lengths, vec![3, 3, 1]);
+/// ```
+pub fn concat_chunked(arrays: &[&dyn Array], chunk_size: usize) -> Result<Vec<ArrayRef>> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+    if chunk_size == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "concat_chunked requires a chunk_size greater than 0".to_string(),
+        ));
+    }
+
+    let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    let expanded = expand_null_arrays(&arrays);
+    let arrays = match &expanded {
+        Some(expanded) => expanded.iter().collect(),
+        None => arrays,
+    };
+    check_data_types(&arrays)?;
+
+    let len = arrays.iter().map(|array| array.len()).sum::<usize>();
+    let mut chunks = Vec::with_capacity(bit_util::ceil(len, chunk_size));
+    // the current input, and the first of its rows not yet in a chunk
+    let (mut index, mut start) = (0, 0);
+    for chunk_start in (0..len).step_by(chunk_size) {
+        let chunk_len = chunk_size.min(len - chunk_start);
+
+        // the (index, start, end) of the rows of each input in this chunk
+        let mut slices = vec![];
+        let mut remaining = chunk_len;
+        while remaining > 0 {
+            let end = arrays[index].len().min(start + remaining);
+            if end > start {
+                slices.push((index, start, end));
+                remaining -= end - start;
+            }
+            if end == arrays[index].len() {
+                index += 1;
+                start = 0;
+            } else {
+                start = end;
+            }
+        }
+
+        let chunk_arrays = slices.iter().map(|(i, _, _)| arrays[*i]).collect();
+        let mut mutable = MutableArrayData::new(chunk_arrays, false, chunk_len);
+        for (i, (_, start, end)) in slices.iter().enumerate() {
+            mutable.extend(i, *start, *end)
+        }
+        chunks.push(make_array(mutable.freeze()));
+    }
+
+    Ok(chunks)
+}
+
+/// Concatenates the window of `length` rows starting at `offset` of the
+/// concatenation of `arrays`, which must all be of the same type.
+///
+/// This is equivalent to slicing the result of [concat], without copying the
+/// rows outside of the window: inputs entirely outside of it are skipped and
+/// the others are sliced to the rows inside of it before being concatenated.
+///
+/// An error is returned if `offset + length` is greater than the total length
+/// of `arrays`.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array};
+/// use arrow::compute::concat_slice;
+///
+/// let array = concat_slice(
+///     &[&Int32Array::from(vec![1, 2, 3]), &Int32Array::from(vec![4, 5])],
+///     2,
+///     2,
+/// ).unwrap();
+/// assert_eq!(array.as_ref(), &Int32Array::from(vec![3, 4]) as &dyn Array);
+/// ```
+pub fn concat_slice(
+    arrays: &[&dyn Array],
+    offset: usize,
+    length: usize,
+) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    check_data_types(&arrays)?;
+
+    let len = arrays.iter().map(|array| array.len()).sum::<usize>();
+    if offset.saturating_add(length) > len {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "concat_slice of {} rows at offset {} is out of bounds of the {} rows of arrays",
+            length, offset, len
+        )));
+    }
+    let end = offset + length;
+
+    // the rows of each input inside of the window
+    let mut array_start = 0;
+    let mut slices = vec![];
+    for array in &arrays {
+        let array_end = array_start + array.len();
+        let start = offset.max(array_start);
+        let stop = end.min(array_end);
+        if start < stop {
+            slices.push(array.slice(start - array_start, stop - start));
+        }
+        array_start = array_end;
+    }
+
+    match slices.is_empty() {
+        true => Ok(new_empty_array(arrays[0].data_type())),
+        false => {
+            let slices = slices.iter().collect::<Vec<_>>();
+            Ok(make_array(concat_data(&slices)?))
+        }
+    }
+}
+
+/// Concatenates `n` copies of `array` into a single [ArrayRef].
+///
+/// This is equivalent to, but cheaper than, calling [concat] with `n`
+/// references to `array`. If `n` is 0 an empty array is returned.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array};
+/// use arrow::compute::concat_n;
+///
+/// let array = concat_n(&Int32Array::from(vec![1, 2]), 3).unwrap();
+/// assert_eq!(array.as_ref(), &Int32Array::from(vec![1, 2, 1, 2, 1, 2]) as &dyn Array);
+/// ```
+pub fn concat_n(array: &dyn Array, n: usize) -> Result<ArrayRef> {
+    match n {
+        0 => return Ok(new_empty_array(array.data_type())),
+        1 => return Ok(array.slice(0, array.len())),
+        _ => {}
+    }
+
+    Ok(make_array(concat_repeated(array.data(), n)?))
+}
+
+/// Concatenates `n` copies of `data` into a single [ArrayData], extending from
+/// a single source rather than switching between `n` identical ones.
+fn concat_repeated(data: &ArrayData, n: usize) -> Result<ArrayData> {
+    check_supported(data.data_type())?;
+    let capacities = concat_capacities(&[data], n)?;
+    let mut mutable = MutableArrayData::with_capacities(vec![data], false, capacities);
+
+    for _ in 0..n {
+        mutable.extend(0, 0, data.len())
+    }
+
+    Ok(mutable.freeze())
+}
+
+/// Concatenates the rows of `arrays` selected by the corresponding `masks` into
+/// a single [ArrayRef].
+///
+/// This is equivalent to, but cheaper than, calling [concat] on the result of
+/// [filter](crate::compute::filter) for each array, as the selected rows are
+/// copied once, one contiguous run at a time. Like [filter](crate::compute::filter),
+/// null mask slots are treated as `false`.
+///
+/// An error is returned if `arrays` is empty, if `arrays` and `masks` have
+/// different lengths, if a mask is not the same length as its array or if the
+/// arrays are not of the same type.
+///
+/// ```
+/// use arrow::array::{Array, BooleanArray, Int32Array};
+/// use arrow::compute::concat_filtered;
+///
+/// let array = concat_filtered(
+///     &[&Int32Array::from(vec![1, 2, 3]), &Int32Array::from(vec![4, 5])],
+///     &[
+///         &BooleanArray::from(vec![true, false, true]),
+///         &BooleanArray::from(vec![false, true]),
+///     ],
+/// ).unwrap();
+/// assert_eq!(array.as_ref(), &Int32Array::from(vec![1, 3, 5]) as &dyn Array);
+/// ```
+pub fn concat_filtered(
+    arrays: &[&dyn Array],
+    masks: &[&BooleanArray],
+) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+    if arrays.len() != masks.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "concat_filtered requires one mask per array, got {} arrays and {} masks",
+            arrays.len(),
+            masks.len()
+        )));
+    }
+    if let Some((i, (array, mask))) = arrays
+        .iter()
+        .zip(masks)
+        .enumerate()
+        .find(|(_, (array, mask))| array.len() != mask.len())
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "masks[{}] has length {} but arrays[{}] has length {}",
+            i,
+            mask.len(),
+            i,
+            array.len()
+        )));
+    }
+
+    let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    check_data_types(&arrays)?;
+
+    let masks = masks
+        .iter()
+        .map(|mask| match mask.null_count() {
+            0 => BooleanArray::from(mask.data().clone()),
+            _ => prep_null_mask_filter(mask),
+        })
+        .collect::<Vec<_>>();
+
+    let capacity = masks
+        .iter()
+        .map(|mask| {
+            mask.values()
+                .count_set_bits_offset(mask.offset(), mask.len())
+        })
+        .sum();
+    let mut mutable = MutableArrayData::new(arrays, false, capacity);
+
+    for (i, mask) in masks.iter().enumerate() {
+        for (start, end) in SlicesIterator::new(mask) {
+            mutable.extend(i, start, end)
+        }
+    }
+
+    Ok(make_array(mutable.freeze()))
+}
+
+/// Gathers the rows of each of `arrays` selected by the corresponding index
+/// array of `indices` and concatenates them into a single [ArrayRef].
+///
+/// This is equivalent to calling [take](crate::compute::take) on every input
+/// and concatenating the results, without allocating the intermediate arrays.
+///
+/// A null index produces a null slot in the output, while an index that is
+/// out of bounds of its array returns an error.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array};
+/// use arrow::compute::gather_concat;
+///
+/// let array = gather_concat(
+///     &[&Int32Array::from(vec![1, 2, 3]), &Int32Array::from(vec![4, 5])],
+///     &[
+///         &Int32Array::from(vec![Some(2), None, Some(0)]),
+///         &Int32Array::from(vec![1]),
+///     ],
+/// ).unwrap();
+/// assert_eq!(
+///     array.as_ref(),
+///     &Int32Array::from(vec![Some(3), None, Some(1), Some(5)]) as &dyn Array
+/// );
+/// ```
+pub fn gather_concat(
+    arrays: &[&dyn Array],
+    indices: &[&PrimitiveArray<Int32Type>],
+) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+    if arrays.len() != indices.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "gather_concat requires one index array per array, got {} arrays and {} index arrays",
+            arrays.len(),
+            indices.len()
+        )));
+    }
+
+    let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    check_data_types(&arrays)?;
+
+    for (array, indices) in arrays.iter().zip(indices) {
+        if let Some(index) = indices
+            .iter()
+            .flatten()
+            .find(|&index| index < 0 || index as usize >= array.len())
+        {
+            return Err(ArrowError::ComputeError(format!(
+                "Array index out of bounds, cannot get item at index {} from {} entries",
+                index,
+                array.len()
+            )));
+        }
+    }
+
+    let capacity = indices.iter().map(|indices| indices.len()).sum();
+    let use_nulls = indices.iter().any(|indices| indices.null_count() > 0);
+    let mut mutable = MutableArrayData::new(arrays, use_nulls, capacity);
+
+    for (i, indices) in indices.iter().enumerate() {
+        // extend by runs of consecutive indices rather than one row at a time
+        let mut run: Option<(usize, usize)> = None;
+        for index in indices.iter() {
+            match (index.map(|index| index as usize), run) {
+                (Some(index), Some((start, end))) if index == end => {
+                    run = Some((start, end + 1))
+                }
+                (index, _) => {
+                    if let Some((start, end)) = run {
+                        mutable.extend(i, start, end);
+                    }
+                    run = index.map(|index| (index, index + 1));
+                    if run.is_none() {
+                        mutable.extend_nulls(1);
+                    }
+                }
+            }
+        }
+        if let Some((start, end)) = run {
+            mutable.extend(i, start, end);
+        }
+    }
+
+    Ok(make_array(mutable.freeze()))
+}
+
+/// Merges `arrays`, which must all be of the same type and each sorted
+/// according to `options`, into a single sorted [ArrayRef].
+///
+/// Unlike [concat], which appends `arrays` one after the other, this performs
+/// a k-way merge of the sorted inputs, copying consecutive rows taken from the
+/// same input at once. Equal rows are taken from the inputs in order, so the
+/// merge is stable. Each output row is picked by comparing the next row of
+/// every input, so this is best suited to a small number of inputs.
+///
+/// The inputs are assumed to be sorted, which is only checked in debug builds:
+/// merging unsorted inputs returns an unsorted array. Only the types supported
+/// by [build_compare] can be merged.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array};
+/// use arrow::compute::{concat_sorted, SortOptions};
+///
+/// let array = concat_sorted(
+///     &[&Int32Array::from(vec![1, 3, 5]), &Int32Array::from(vec![2, 4, 6])],
+///     SortOptions::default(),
+/// ).unwrap();
+/// assert_eq!(array.as_ref(), &Int32Array::from(vec![1, 2, 3, 4, 5, 6]) as &dyn Array);
+/// ```
+pub fn concat_sorted(arrays: &[&dyn Array], options: SortOptions) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let data = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    check_data_types(&data)?;
+
+    // comparators[i][j] compares the values of arrays[i] to those of arrays[j]
+    let comparators = arrays
+        .iter()
+        .map(|left| {
+            arrays
+                .iter()
+                .map(|right| build_compare(*left, *right))
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let cmp = |i: usize, a: usize, j: usize, b: usize| match (
+        arrays[i].is_valid(a),
+        arrays[j].is_valid(b),
+    ) {
+        (false, false) => Ordering::Equal,
+        (false, true) if options.nulls_first => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (true, false) if options.nulls_first => Ordering::Greater,
+        (true, false) => Ordering::Less,
+        (true, true) if options.descending => comparators[i][j](a, b).reverse(),
+        (true, true) => comparators[i][j](a, b),
+    };
+
+    let capacities = concat_capacities(&data, 1)?;
+    let mut mutable = MutableArrayData::with_capacities(data, false, capacities);
+
+    // the next row of each input, and the run of rows of the same input
+    let mut heads = vec![0; arrays.len()];
+    let mut run = (0, 0, 0);
+    loop {
+        let next = (0..arrays.len())
+            .filter(|j| heads[*j] < arrays[*j].len())
+            .reduce(|best, j| match cmp(j, heads[j], best, heads[best]) {
+                Ordering::Less => j,
+                _ => best,
+            });
+        let j = match next {
+            Some(j) => j,
+            None => break,
+        };
+
+        let (run_j, start, end) = run;
+        if run_j != j || end != heads[j] {
+            mutable.extend(run_j, start, end);
+            run = (j, heads[j], heads[j]);
+        }
+        debug_assert!(
+            heads[j] == 0 || cmp(j, heads[j] - 1, j, heads[j]) != Ordering::Greater,
+            "arrays[{}] is not sorted",
+            j
+        );
+        heads[j] += 1;
+        run.2 = heads[j];
+    }
+    let (run_j, start, end) = run;
+    mutable.extend(run_j, start, end);
+
+    Ok(make_array(mutable.freeze()))
+}
+
+/// Merges the maps of each row of `arrays`, which must all be of the same type
+/// and length, into a single [MapArray].
+///
+/// Unlike [concat], which appends the rows of `arrays`, this concatenates the
+/// entries of the maps at the same row of every input, keeping only the last
+/// occurrence of each key: a key present in several inputs takes the value of
+/// the last of them, at the position of its first occurrence. A row is null if
+/// it is null in all of `arrays`, null rows of some inputs being treated as
+/// empty maps otherwise. Only the key types supported by [build_compare] can be
+/// merged.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array, Int32Builder, MapBuilder, StringBuilder};
+/// use arrow::compute::concat_maps_merge_keys;
+///
+/// let mut builder = MapBuilder::new(None, StringBuilder::new(2), Int32Builder::new(2));
+/// builder.keys().append_value("a").unwrap();
+/// builder.values().append_value(1).unwrap();
+/// builder.keys().append_value("b").unwrap();
+/// builder.values().append_value(2).unwrap();
+/// builder.append(true).unwrap();
+/// let map_1 = builder.finish();
+///
+/// builder.keys().append_value("a").unwrap();
+/// builder.values().append_value(3).unwrap();
+/// builder.append(true).unwrap();
+/// let map_2 = builder.finish();
+///
+/// let merged = concat_maps_merge_keys(&[&map_1, &map_2]).unwrap();
+/// assert_eq!(merged.value_length(0), 2);
+/// assert_eq!(merged.values().as_ref(), &Int32Array::from(vec![3, 2]) as &dyn Array);
+/// ```
+pub fn concat_maps_merge_keys(arrays: &[&MapArray]) -> Result<MapArray> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+    let len = arrays[0].len();
+    if let Some((i, array)) = arrays
+        .iter()
+        .enumerate()
+        .find(|(_, array)| array.len() != len)
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "concat_maps_merge_keys requires arrays of the same length, arrays[0] has length {} but arrays[{}] has length {}",
+            len,
+            i,
+            array.len()
+        )));
+    }
+
+    let data = arrays.iter().map(|array| array.data()).collect::<Vec<_>>();
+    check_data_types(&data)?;
+
+    // comparators[i][j] compares the keys of arrays[i] to those of arrays[j]
+    let keys = arrays.iter().map(|array| array.keys()).collect::<Vec<_>>();
+    let comparators = keys
+        .iter()
+        .map(|left| {
+            keys.iter()
+                .map(|right| build_compare(left.as_ref(), right.as_ref()))
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let entries = data
+        .iter()
+        .map(|data| &data.child_data()[0])
+        .collect::<Vec<_>>();
+    let mut mutable = MutableArrayData::new(entries, false, 0);
+    let mut offsets = BufferBuilder::<i32>::new(len + 1);
+    offsets.append(0);
+    let mut nulls = BooleanBufferBuilder::new(len);
+
+    // the input and index of the entry of each distinct key of a row
+    let mut row = vec![];
+    let mut offset = 0i32;
+    for i in 0..len {
+        row.clear();
+        for (j, array) in arrays.iter().enumerate().filter(|(_, a)| a.is_valid(i)) {
+            let (start, end) = (array.value_offsets()[i], array.value_offsets()[i + 1]);
+            for k in start as usize..end as usize {
+                match row.iter_mut().find(|(l, m): &&mut (usize, usize)| {
+                    comparators[*l][j](*m, k).is_eq()
+                }) {
+                    Some(entry) => *entry = (j, k),
+                    None => row.push((j, k)),
+                }
+            }
+        }
+
+        for (j, k) in &row {
+            mutable.extend(*j, *k, *k + 1);
+        }
+        offset = i32::try_from(row.len())
+            .ok()
+            .and_then(|len| offset.checked_add(len))
+            .ok_or_else(|| {
+                ArrowError::ComputeError(
+                    "concat_maps_merge_keys would overflow the i32 offsets of the result"
+                        .to_string(),
+                )
+            })?;
+        offsets.append(offset);
+        nulls.append(arrays.iter().any(|array| array.is_valid(i)));
+    }
+
+    let data = ArrayData::builder(arrays[0].data_type().clone())
+        .len(len)
+        .add_buffer(offsets.finish())
+        .null_bit_buffer(Some(nulls.finish()))
+        .add_child_data(mutable.freeze())
+        .build()?;
+    Ok(MapArray::from(data))
+}
+
+/// Concatenates `arrays`, which must all be of the same type, into a single
+/// [DictionaryArray] with keys of type `K`.
+///
+/// The values of all inputs are deduplicated into a single dictionary and null
+/// slots are kept as null keys. As this relies on [cast] to pack the values,
+/// only the value types that can be cast to a dictionary are supported:
+/// integers and [DataType::Utf8].
+///
+/// An error is returned if the number of distinct values cannot be indexed by
+/// `K`.
+///
+/// ```
+/// use arrow::array::{Array, DictionaryArray, StringArray};
+/// use arrow::compute::concat_to_dictionary;
+/// use arrow::datatypes::Int8Type;
+///
+/// let array = concat_to_dictionary::<Int8Type>(&[
+///     &StringArray::from(vec!["a", "b", "a"]),
+///     &StringArray::from(vec![Some("b"), None]),
+/// ]).unwrap();
+/// let array = array.as_any().downcast_ref::<DictionaryArray<Int8Type>>().unwrap();
+/// assert_eq!(array.len(), 5);
+/// assert_eq!(array.values().len(), 2);
+/// ```
+pub fn concat_to_dictionary<K: ArrowDictionaryKeyType>(
+    arrays: &[&dyn Array],
+) -> Result<ArrayRef> {
+    let array = concat(arrays)?;
+    let data_type =
+        DataType::Dictionary(Box::new(K::DATA_TYPE), Box::new(array.data_type().clone()));
+    cast(&array, &data_type)
+}
+
+/// Concatenates dictionary-encoded `arrays` with keys of type `K` into a single
+/// [DictionaryArray] whose dictionary is `target_values`.
+///
+/// Every key of the inputs is remapped to the position of its value in
+/// `target_values`, so that arrays concatenated against the same
+/// `target_values` share a dictionary. Null keys, and keys referencing null
+/// values, are kept as null keys.
+///
+/// An error is returned if a value referenced by any of `arrays` is not
+/// present in `target_values`, or if the value type of `arrays` does not match
+/// that of `target_values`. Only the value types supported by
+/// [build_compare] can be looked up.
+///
+/// ```
+/// use arrow::array::{Array, DictionaryArray, Int8Array, StringArray};
+/// use arrow::compute::concat_against_dictionary;
+/// use arrow::datatypes::Int8Type;
+///
+/// let a: DictionaryArray<Int8Type> = vec!["b", "a"].into_iter().collect();
+/// let b: DictionaryArray<Int8Type> = vec!["c"].into_iter().collect();
+/// let target = StringArray::from(vec!["a", "b", "c"]);
+///
+/// let array = concat_against_dictionary::<Int8Type>(&[&a, &b], &target).unwrap();
+/// let array = array.as_any().downcast_ref::<DictionaryArray<Int8Type>>().unwrap();
+/// assert_eq!(array.keys(), &Int8Array::from(vec![1, 0, 2]));
+/// assert_eq!(array.values().as_ref(), &target as &dyn Array);
+/// ```
+pub fn concat_against_dictionary<K: ArrowDictionaryKeyType>(
+    arrays: &[&dyn Array],
+    target_values: &dyn Array,
+) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let data = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    check_data_types(&data)?;
+
+    let data_type = DataType::Dictionary(
+        Box::new(K::DATA_TYPE),
+        Box::new(target_values.data_type().clone()),
+    );
+    if data[0].data_type() != &data_type {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "concat_against_dictionary expects arrays of type {:?}, got {:?}",
+            data_type,
+            data[0].data_type()
+        )));
+    }
+    if target_values.len() as u64 > dictionary_key_max(&K::DATA_TYPE) + 1 {
+        return Err(ArrowError::DictionaryKeyOverflowError);
+    }
+
+    // positions of the valid target values, sorted by value for lookups
+    let target_cmp = build_compare(target_values, target_values)?;
+    let mut sorted = (0..target_values.len())
+        .filter(|i| target_values.is_valid(*i))
+        .collect::<Vec<_>>();
+    sorted.sort_by(|a, b| target_cmp(*a, *b));
+
+    let mut keys = Vec::with_capacity(arrays.iter().map(|a| a.len()).sum());
+    for (i, array) in arrays.iter().enumerate() {
+        let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+        let values = array.values();
+        let cmp = build_compare(values.as_ref(), target_values)?;
+
+        // `None` for values absent from `target_values`, which are only an
+        // error if referenced by a key
+        let remap = (0..values.len())
+            .map(|v| match values.is_valid(v) {
+                true => sorted
+                    .binary_search_by(|t| cmp(v, *t).reverse())
+                    .ok()
+                    .map(|pos| K::Native::from_usize(sorted[pos])),
+                false => Some(None),
+            })
+            .collect::<Vec<_>>();
+
+        for key in array.keys().iter() {
+            let key = match key {
+                Some(key) => {
+                    let v = key.to_usize().unwrap();
+                    remap[v].ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!(
+                            "value at index {} of the dictionary of arrays[{}] is not present in target_values",
+                            v, i
+                        ))
+                    })?
+                }
+                None => None,
+            };
+            keys.push(key);
+        }
+    }
+
+    let keys = keys.into_iter().collect::<PrimitiveArray<K>>();
+    Ok(Arc::new(DictionaryArray::<K>::try_new(
+        &keys,
+        target_values,
+    )?))
+}
+
+/// Concatenates dictionary-encoded `arrays` with keys of type `K` into a single
+/// [DictionaryArray], merging their dictionaries.
+///
+/// Unlike [concat], which appends the dictionaries of all inputs, values
+/// present in several dictionaries are only kept once. The merge starts from
+/// the largest dictionary of `arrays`, which is kept as is, so that only the
+/// values missing from it are looked up and added, in input order, from the
+/// other dictionaries. Null keys, and keys referencing null values, are kept as
+/// null keys.
+///
+/// An error is returned if `arrays` are not all of the same dictionary type
+/// with keys of type `K`, or if the merged dictionary cannot be indexed by `K`.
+/// Only the value types supported by [build_compare] can be merged.
+///
+/// ```
+/// use arrow::array::{Array, DictionaryArray, Int8Array, StringArray};
+/// use arrow::compute::concat_dictionaries_merge;
+/// use arrow::datatypes::Int8Type;
+///
+/// let a: DictionaryArray<Int8Type> = vec!["c", "d"].into_iter().collect();
+/// let b: DictionaryArray<Int8Type> = vec!["a", "b", "c"].into_iter().collect();
+///
+/// let array = concat_dictionaries_merge::<Int8Type>(&[&a, &b]).unwrap();
+/// let array = array.as_any().downcast_ref::<DictionaryArray<Int8Type>>().unwrap();
+/// assert_eq!(array.keys(), &Int8Array::from(vec![2, 3, 0, 1, 2]));
+/// assert_eq!(
+///     array.values().as_ref(),
+///     &StringArray::from(vec!["a", "b", "c", "d"]) as &dyn Array
+/// );
+/// ```
+pub fn concat_dictionaries_merge<K: ArrowDictionaryKeyType>(
+    arrays: &[&dyn Array],
+) -> Result<ArrayRef> {
+    concat_dictionaries_with_mapping::<K>(arrays).map(|(array, _)| array)
+}
+
+/// Concatenates dictionary-encoded `arrays` with keys of type `K` into a single
+/// [DictionaryArray], merging their dictionaries like
+/// [concat_dictionaries_merge], and also returns how the dictionary of each
+/// input maps to the merged dictionary.
+///
+/// The mapping of `arrays[i]` holds, for each index of its dictionary, the
+/// index of the same value in the merged dictionary, or `None` for null
+/// values. This allows rewriting other columns correlated with the keys of the
+/// inputs.
+///
+/// ```
+/// use arrow::array::DictionaryArray;
+/// use arrow::compute::concat_dictionaries_with_mapping;
+/// use arrow::datatypes::Int8Type;
+///
+/// let a: DictionaryArray<Int8Type> = vec!["a", "b", "c"].into_iter().collect();
+/// let b: DictionaryArray<Int8Type> = vec!["c", "d"].into_iter().collect();
+///
+/// let (_, mapping) = concat_dictionaries_with_mapping::<Int8Type>(&[&a, &b]).unwrap();
+/// assert_eq!(mapping, vec![vec![Some(0), Some(1), Some(2)], vec![Some(2), Some(3)]]);
+/// ```
+pub fn concat_dictionaries_with_mapping<K: ArrowDictionaryKeyType>(
+    arrays: &[&dyn Array],
+) -> Result<(ArrayRef, Vec<Vec<Option<usize>>>)> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let data = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    check_data_types(&data)?;
+    let arrays = arrays
+        .iter()
+        .map(|array| {
+            array
+                .as_any()
+                .downcast_ref::<DictionaryArray<K>>()
+                .ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "concat_dictionaries_merge expects dictionary arrays with keys of type {:?}, got {:?}",
+                        K::DATA_TYPE,
+                        array.data_type()
+                    ))
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // the first of the largest dictionaries is the base of the merge
+    let base = (0..arrays.len())
+        .rev()
+        .max_by_key(|i| arrays[*i].values().len())
+        .unwrap();
+    let mut values = arrays[base].values().clone();
+    let values_cmp = build_compare(values.as_ref(), values.as_ref())?;
+    let mut sorted = (0..values.len())
+        .filter(|v| values.is_valid(*v))
+        .collect::<Vec<_>>();
+    sorted.sort_by(|a, b| values_cmp(*a, *b));
+
+    // the position in the merged dictionary of the values of each input
+    let mut remaps = vec![vec![]; arrays.len()];
+    remaps[base] = (0..values.len())
+        .map(|v| values.is_valid(v).then(|| v))
+        .collect();
+    for (i, array) in arrays.iter().enumerate().filter(|(i, _)| *i != base) {
+        let local = array.values();
+        let cmp = build_compare(local.as_ref(), values.as_ref())?;
+        let local_cmp = build_compare(local.as_ref(), local.as_ref())?;
+
+        let mut remap = vec![None; local.len()];
+        let mut missing = vec![];
+        for v in (0..local.len()).filter(|v| local.is_valid(*v)) {
+            match sorted.binary_search_by(|t| cmp(v, *t).reverse()) {
+                Ok(pos) => remap[v] = Some(sorted[pos]),
+                Err(_) => missing.push(v),
+            }
+        }
+        if missing.is_empty() {
+            remaps[i] = remap;
+            continue;
+        }
+
+        // values repeated in the dictionary of the input are only added once
+        let mut new_values: Vec<u32> = vec![];
+        let mut order = missing.clone();
+        order.sort_by(|a, b| local_cmp(*a, *b));
+        let mut first = vec![0; local.len()];
+        for (j, v) in order.iter().enumerate() {
+            first[*v] = match j > 0 && local_cmp(order[j - 1], *v).is_eq() {
+                true => first[order[j - 1]],
+                false => *v,
+            };
+        }
+        for v in missing {
+            if first[v] == v {
+                remap[v] = Some(values.len() + new_values.len());
+                new_values.push(v as u32);
+            } else {
+                remap[v] = remap[first[v]];
+            }
+        }
+
+        let len = values.len() + new_values.len();
+        if len as u64 > dictionary_key_max(&K::DATA_TYPE) + 1 {
+            return Err(ArrowError::DictionaryKeyOverflowError);
+        }
+        let new_values = take(local.as_ref(), &UInt32Array::from(new_values), None)?;
+        values = concat(&[values.as_ref(), new_values.as_ref()])?;
+
+        let values_cmp = build_compare(values.as_ref(), values.as_ref())?;
+        sorted = (0..len).filter(|v| values.is_valid(*v)).collect();
+        sorted.sort_by(|a, b| values_cmp(*a, *b));
+        remaps[i] = remap;
+    }
+
+    let keys = arrays
+        .iter()
+        .zip(&remaps)
+        .flat_map(|(array, remap)| {
+            array.keys().iter().map(move |key| {
+                key.and_then(|key| remap[key.to_usize().unwrap()])
+                    .map(|v| K::Native::from_usize(v).unwrap())
+            })
+        })
+        .collect::<PrimitiveArray<K>>();
+    let array = DictionaryArray::<K>::try_new(&keys, values.as_ref())?;
+    Ok((Arc::new(array), remaps))
+}
+
+/// Incrementally concatenates arrays of the same [DataType] into a single
+/// [ArrayRef].
+///
+/// Arrays of fixed width, boolean, string and binary types are copied into
+/// buffers owned by the builder as they are pushed, so they do not need to be
+/// kept alive until [ConcatBuilder::finish]. Arrays of other types, such as
+/// nested and dictionary types, are kept until [ConcatBuilder::finish], which
+/// concatenates them all at once like [concat] does.
+///
+/// Either way, the result is the same as calling [concat] once on all the
+/// pushed arrays, without requiring all of them to be known up front.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array};
+/// use arrow::compute::ConcatBuilder;
+/// use arrow::datatypes::DataType;
+///
+/// let mut builder = ConcatBuilder::new(DataType::Int32);
+/// builder.push(&Int32Array::from(vec![1, 2])).unwrap();
+/// builder.push(&Int32Array::from(vec![3])).unwrap();
+/// let array = builder.finish().unwrap();
+/// assert_eq!(array.as_ref(), &Int32Array::from(vec![1, 2, 3]) as &dyn Array);
+/// ```
+#[derive(Debug)]
+pub struct ConcatBuilder {
+    data_type: DataType,
+    len: usize,
+    /// the validity bitmap of the pushed slots, only allocated once a null
+    /// slot is pushed, and unused for [ConcatValues::Deferred]
+    nulls: Option<MutableBuffer>,
+    values: ConcatValues,
+}
+
+/// The values of the slots pushed to a [ConcatBuilder]
+#[derive(Debug)]
+enum ConcatValues {
+    /// values of a fixed width type of the given width in bytes
+    FixedWidth(MutableBuffer, usize),
+    /// bit packed boolean values
+    Boolean(MutableBuffer),
+    /// the offsets and values of a string or binary type
+    Binary(MutableBuffer, MutableBuffer),
+    /// arrays of other types, concatenated when finished
+    Deferred(Vec<ArrayData>),
+}
+
+impl ConcatBuilder {
+    /// Creates a new [ConcatBuilder] for arrays of type `data_type`
+    pub fn new(data_type: DataType) -> Self {
+        Self::with_capacity(data_type, 0)
+    }
+
+    /// Creates a new [ConcatBuilder] for arrays of type `data_type`, with
+    /// room for `capacity` slots before reallocating.
+    ///
+    /// The values of string and binary types are not counted, their buffer
+    /// grows as arrays are pushed.
+    pub fn with_capacity(data_type: DataType, capacity: usize) -> Self {
+        let values = match &data_type {
+            DataType::Boolean => {
+                ConcatValues::Boolean(MutableBuffer::new(bit_util::ceil(capacity, 8)))
+            }
+            DataType::Utf8 | DataType::Binary => {
+                let mut offsets = MutableBuffer::new((capacity + 1) * 4);
+                offsets.push(0i32);
+                ConcatValues::Binary(offsets, MutableBuffer::new(0))
+            }
+            DataType::LargeUtf8 | DataType::LargeBinary => {
+                let mut offsets = MutableBuffer::new((capacity + 1) * 8);
+                offsets.push(0i64);
+                ConcatValues::Binary(offsets, MutableBuffer::new(0))
+            }
+            data_type => match fixed_byte_width(data_type) {
+                Some(byte_width) => ConcatValues::FixedWidth(
+                    MutableBuffer::new(capacity * byte_width),
+                    byte_width,
+                ),
+                None => ConcatValues::Deferred(vec![]),
+            },
+        };
+        Self {
+            data_type,
+            len: 0,
+            nulls: None,
+            values,
+        }
+    }
+
+    /// Creates a new [ConcatBuilder] starting with `array`, typically the
+    /// result of a previous concatenation, to which more arrays can be pushed.
+    ///
+    /// For the types whose pushed arrays are copied, see [ConcatBuilder], the
+    /// buffers of `array` are reused without copying them if `array` holds the
+    /// only reference to them, as the result of [concat] does until it is
+    /// cloned. Pushed arrays are then appended to their spare capacity, which
+    /// grows as needed. Otherwise, including when `array` is a slice, its values
+    /// are copied once.
+    ///
+    /// ```
+    /// use arrow::array::{Array, Int32Array};
+    /// use arrow::compute::{concat, ConcatBuilder};
+    ///
+    /// let array = concat(&[&Int32Array::from(vec![1]), &Int32Array::from(vec![2])]).unwrap();
+    /// let values = array.data().buffers()[0].as_ptr();
+    ///
+    /// let mut builder = ConcatBuilder::from_array(array);
+    /// builder.push(&Int32Array::from(vec![3])).unwrap();
+    /// let array = builder.finish().unwrap();
+    /// assert_eq!(array.as_ref(), &Int32Array::from(vec![1, 2, 3]) as &dyn Array);
+    /// // the value was appended to the buffer of the first result
+    /// assert_eq!(array.data().buffers()[0].as_ptr(), values);
+    /// ```
+    pub fn from_array(array: ArrayRef) -> Self {
+        let data = array.data().clone();
+        // release the reference of `array` to its buffers
+        drop(array);
+        let mut builder = Self::with_capacity(data.data_type().clone(), data.len());
+        if data.offset() != 0 || matches!(builder.values, ConcatValues::Deferred(_)) {
+            // a single array always fits in the offsets of its own type
+            builder.append(&data).unwrap();
+            return builder;
+        }
+
+        let len = data.len();
+        let nulls = match data.null_count() {
+            0 => None,
+            _ => data.null_buffer().cloned(),
+        };
+        let mut buffers = data.buffers().to_vec().into_iter();
+        let values_len = match &builder.data_type {
+            DataType::Utf8 | DataType::Binary => data.buffer::<i32>(0)[len] as usize,
+            DataType::LargeUtf8 | DataType::LargeBinary => {
+                data.buffer::<i64>(0)[len] as usize
+            }
+            _ => 0,
+        };
+        drop(data);
+
+        builder.values = match builder.values {
+            ConcatValues::FixedWidth(_, byte_width) => ConcatValues::FixedWidth(
+                reuse_buffer(buffers.next().unwrap(), len * byte_width),
+                byte_width,
+            ),
+            ConcatValues::Boolean(_) => {
+                ConcatValues::Boolean(reuse_bits(buffers.next().unwrap(), len))
+            }
+            ConcatValues::Binary(_, _) => {
+                let offset_width = match builder.data_type {
+                    DataType::Utf8 | DataType::Binary => 4,
+                    _ => 8,
+                };
+                ConcatValues::Binary(
+                    reuse_buffer(buffers.next().unwrap(), (len + 1) * offset_width),
+                    reuse_buffer(buffers.next().unwrap(), values_len),
+                )
+            }
+            ConcatValues::Deferred(_) => unreachable!(),
+        };
+        builder.nulls = nulls.map(|nulls| reuse_bits(nulls, len));
+        builder.len = len;
+        builder
+    }
+
+    /// Appends `array` to the arrays to concatenate.
+    ///
+    /// Returns an error if the type of `array` is not the type of this builder,
+    /// or if the values of a [DataType::Utf8] or [DataType::Binary] builder
+    /// would overflow its `i32` offsets.
+    pub fn push(&mut self, array: &dyn Array) -> Result<()> {
+        if array.data_type() != &self.data_type {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "It is not possible to concatenate arrays of different data types: expected {:?}, got {:?}",
+                self.data_type,
+                array.data_type()
+            )));
+        }
+        check_supported(array.data_type())?;
+        self.append(array.data())
+    }
+
+    /// Appends `data`, of the type of this builder, to the pushed slots
+    fn append(&mut self, data: &ArrayData) -> Result<()> {
+        let len = data.len();
+        match &mut self.values {
+            ConcatValues::FixedWidth(values, byte_width) => {
+                let start = data.offset() * *byte_width;
+                values.extend_from_slice(
+                    &data.buffers()[0].as_slice()[start..start + len * *byte_width],
+                );
+            }
+            ConcatValues::Boolean(values) => append_bits(
+                values,
+                self.len,
+                Some(&data.buffers()[0]),
+                data.offset(),
+                len,
+            ),
+            ConcatValues::Binary(offsets, values) => match self.data_type {
+                DataType::Utf8 | DataType::Binary => {
+                    append_binary::<i32>(offsets, values, data)?
+                }
+                _ => append_binary::<i64>(offsets, values, data)?,
+            },
+            ConcatValues::Deferred(arrays) => {
+                arrays.push(data.clone());
+                self.len += len;
+                return Ok(());
+            }
+        }
+
+        if self.nulls.is_none() && data.null_count() > 0 {
+            let mut nulls = MutableBuffer::new(bit_util::ceil(self.len + len, 8));
+            append_bits(&mut nulls, 0, None, 0, self.len);
+            self.nulls = Some(nulls);
+        }
+        if let Some(nulls) = self.nulls.as_mut() {
+            append_bits(nulls, self.len, data.null_buffer(), data.offset(), len);
+        }
+        self.len += len;
+        Ok(())
+    }
+
+    /// Returns the number of slots pushed so far
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no slots have been pushed so far
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the [DataType] of the arrays of this builder
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Returns a single [ArrayRef] of all the pushed slots.
+    ///
+    /// Returns an empty array if no arrays were pushed.
+    pub fn finish(self) -> Result<ArrayRef> {
+        let buffers = match self.values {
+            ConcatValues::FixedWidth(values, _) | ConcatValues::Boolean(values) => {
+                vec![values.into()]
+            }
+            ConcatValues::Binary(offsets, values) => vec![offsets.into(), values.into()],
+            ConcatValues::Deferred(arrays) => {
+                return match arrays.len() {
+                    0 => Ok(new_empty_array(&self.data_type)),
+                    _ => Ok(make_array(concat_data(&arrays.iter().collect::<Vec<_>>())?)),
+                }
+            }
+        };
+
+        let builder = ArrayData::builder(self.data_type)
+            .len(self.len)
+            .null_bit_buffer(self.nulls.map(Into::into))
+            .buffers(buffers);
+        // Safety: the buffers were appended the values and validity of arrays
+        // of the same type, with offsets rebased onto the appended values
+        Ok(make_array(unsafe { builder.build_unchecked() }))
+    }
+}
+
+/// Returns the first `len` bytes of `buffer` as a [MutableBuffer], reusing its
+/// memory if possible and copying them otherwise
+fn reuse_buffer(buffer: Buffer, len: usize) -> MutableBuffer {
+    match buffer.into_mutable() {
+        Ok(mut buffer) => {
+            buffer.resize(len, 0);
+            buffer
+        }
+        Err(buffer) => {
+            let mut copy = MutableBuffer::new(len);
+            copy.extend_from_slice(&buffer.as_slice()[..len]);
+            copy
+        }
+    }
+}
+
+/// Returns the first `len` bits of `buffer` as a [MutableBuffer] without any
+/// other bit set, reusing its memory if possible
+fn reuse_bits(buffer: Buffer, len: usize) -> MutableBuffer {
+    let mut buffer = reuse_buffer(buffer, bit_util::ceil(len, 8));
+    if len % 8 != 0 {
+        *buffer.as_slice_mut().last_mut().unwrap() &= (1 << (len % 8)) - 1;
+    }
+    buffer
+}
+
+/// Appends `len` bits of `bits` starting at bit `offset`, or `len` set bits if
+/// `bits` is `None`, after the first `start` bits of `buffer`.
+///
+/// The bits of `buffer` past `start` must not be set.
+fn append_bits(
+    buffer: &mut MutableBuffer,
+    start: usize,
+    bits: Option<&Buffer>,
+    offset: usize,
+    len: usize,
+) {
+    buffer.resize(bit_util::ceil(start + len, 8), 0);
+    match bits {
+        Some(bits) => {
+            set_bits(buffer.as_slice_mut(), bits.as_slice(), start, offset, len);
+        }
+        None => {
+            (start..start + len).for_each(|i| bit_util::set_bit(buffer.as_slice_mut(), i))
+        }
+    }
+}
+
+/// Appends the values of `data`, a string or binary array with offsets of type
+/// `Offset`, to `values`, and their offsets rebased onto `values` to `offsets`
+fn append_binary<Offset: OffsetSizeTrait>(
+    offsets: &mut MutableBuffer,
+    values: &mut MutableBuffer,
+    data: &ArrayData,
+) -> Result<()> {
+    let data_offsets = &data.buffer::<Offset>(0)[..=data.len()];
+    let start = data_offsets[0].to_usize().unwrap();
+    let end = data_offsets[data.len()].to_usize().unwrap();
+    let values_len = values.len() + end - start;
+    if Offset::from_usize(values_len).is_none() {
+        return Err(ArrowError::ComputeError(format!(
+            "concat of {:?} arrays with {} value bytes would overflow i32 offsets, consider casting to {:?} first",
+            data.data_type(),
+            values_len,
+            match data.data_type() {
+                DataType::Utf8 => DataType::LargeUtf8,
+                _ => DataType::LargeBinary,
+            }
+        )));
+    }
+    let base = Offset::from_usize(values.len()).unwrap();
+
+    values.extend_from_slice(&data.buffers()[1].as_slice()[start..end]);
+    offsets.extend(
+        data_offsets[1..]
+            .iter()
+            .map(|offset| *offset - data_offsets[0] + base),
+    );
+    Ok(())
+}
+
+/// Incrementally concatenates arrays of the same [DataType] into a single
+/// [DictionaryArray] with keys of type `K`, interning their values into a
+/// dictionary that grows as arrays are pushed.
+///
+/// Values already in the dictionary are reused, so the dictionary holds each
+/// distinct value once and the keys of previously pushed arrays stay valid.
+/// Null slots are kept as null keys. Like [concat_to_dictionary], only the
+/// value types that can be cast to a dictionary are supported: integers and
+/// [DataType::Utf8].
+///
+/// The values are looked up in an index of the dictionary sorted by value.
+/// Each push only appends its new values to the dictionary, reusing its
+/// buffers like [ConcatBuilder::from_array] does, and merges them into the
+/// index, so neither is rebuilt from scratch.
+///
+/// ```
+/// use arrow::array::{Array, DictionaryArray, Int8Array, StringArray};
+/// use arrow::compute::DictionaryConcatBuilder;
+/// use arrow::datatypes::{DataType, Int8Type};
+///
+/// let mut builder = DictionaryConcatBuilder::<Int8Type>::new(DataType::Utf8);
+/// builder.push_values(&StringArray::from(vec!["a", "b"])).unwrap();
+/// builder.push_values(&StringArray::from(vec!["b", "c"])).unwrap();
+/// let array = builder.finish().unwrap();
+/// let array = array.as_any().downcast_ref::<DictionaryArray<Int8Type>>().unwrap();
+/// assert_eq!(array.keys(), &Int8Array::from(vec![0, 1, 1, 2]));
+/// assert_eq!(array.values().as_ref(), &StringArray::from(vec!["a", "b", "c"]) as &dyn Array);
+/// ```
+#[derive(Debug)]
+pub struct DictionaryConcatBuilder<K: ArrowDictionaryKeyType> {
+    values: ArrayRef,
+    /// positions of `values`, sorted by value for lookups
+    sorted: Vec<usize>,
+    keys: Vec<Option<K::Native>>,
+}
+
+impl<K: ArrowDictionaryKeyType> DictionaryConcatBuilder<K> {
+    /// Creates a new [DictionaryConcatBuilder] for arrays of type `value_type`
+    pub fn new(value_type: DataType) -> Self {
+        Self {
+            values: new_empty_array(&value_type),
+            sorted: vec![],
+            keys: vec![],
+        }
+    }
+
+    /// Appends the values of `array`, adding those not yet in the dictionary
+    /// to it.
+    ///
+    /// Returns an error if the type of `array` is not the value type of this
+    /// builder, or if the dictionary would grow past what `K` can index.
+    pub fn push_values(&mut self, array: &dyn Array) -> Result<()> {
+        if array.data_type() != self.values.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "It is not possible to concatenate arrays of different data types: expected {:?}, got {:?}",
+                self.values.data_type(),
+                array.data_type()
+            )));
+        }
+
+        // deduplicate the values of `array` before looking them up
+        let local = cast(
+            &make_array(array.data().clone()),
+            &DataType::Dictionary(
+                Box::new(DataType::UInt32),
+                Box::new(array.data_type().clone()),
+            ),
+        )?;
+        let local = local
+            .as_any()
+            .downcast_ref::<DictionaryArray<UInt32Type>>()
+            .unwrap();
+        let local_values = local.values();
+
+        let cmp = build_compare(local_values.as_ref(), self.values.as_ref())?;
+        // the local values not in the dictionary, with where they belong in `sorted`
+        let mut new_values = vec![];
+        let remap = (0..local_values.len())
+            .map(
+                |v| match self.sorted.binary_search_by(|t| cmp(v, *t).reverse()) {
+                    Ok(pos) => self.sorted[pos],
+                    Err(pos) => {
+                        new_values.push((pos, v as u32));
+                        self.values.len() + new_values.len() - 1
+                    }
+                },
+            )
+            .collect::<Vec<_>>();
+        // the comparator holds a reference to the values, which are appended to below
+        drop(cmp);
+
+        if !new_values.is_empty() {
+            let old_len = self.values.len();
+            let len = old_len + new_values.len();
+            if len as u64 > dictionary_key_max(&K::DATA_TYPE) + 1 {
+                return Err(ArrowError::DictionaryKeyOverflowError);
+            }
+
+            // merge the new values into `sorted`, ordering those that belong at
+            // the same position by value
+            let cmp = build_compare(local_values.as_ref(), local_values.as_ref())?;
+            let mut inserts = new_values
+                .iter()
+                .enumerate()
+                .map(|(i, (pos, v))| (*pos, *v, old_len + i))
+                .collect::<Vec<_>>();
+            inserts.sort_unstable_by(|a, b| {
+                a.0.cmp(&b.0).then_with(|| cmp(a.1 as usize, b.1 as usize))
+            });
+            let mut sorted = Vec::with_capacity(len);
+            let mut start = 0;
+            for (pos, _, position) in inserts {
+                sorted.extend_from_slice(&self.sorted[start..pos]);
+                sorted.push(position);
+                start = pos;
+            }
+            sorted.extend_from_slice(&self.sorted[start..]);
+            self.sorted = sorted;
+
+            // append to the buffers of the values rather than copying them again
+            let new_values = take(
+                local_values.as_ref(),
+                &new_values.iter().map(|(_, v)| *v).collect::<UInt32Array>(),
+                None,
+            )?;
+            let values = std::mem::replace(
+                &mut self.values,
+                new_empty_array(new_values.data_type()),
+            );
+            let mut builder = ConcatBuilder::from_array(values);
+            builder.push(new_values.as_ref())?;
+            self.values = builder.finish()?;
+        }
+
+        self.keys.extend(local.keys().iter().map(|key| {
+            key.map(|key| K::Native::from_usize(remap[key as usize]).unwrap())
+        }));
+        Ok(())
+    }
+
+    /// Returns the number of slots pushed so far
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns true if no slots have been pushed so far
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the dictionary of the values pushed so far.
+    ///
+    /// If the returned array is cloned, the next push that adds values copies
+    /// the dictionary instead of appending to it.
+    pub fn values(&self) -> &ArrayRef {
+        &self.values
+    }
+
+    /// Returns a [DictionaryArray] of all the pushed values
+    pub fn finish(self) -> Result<ArrayRef> {
+        let keys = self.keys.into_iter().collect::<PrimitiveArray<K>>();
+        Ok(Arc::new(DictionaryArray::<K>::try_new(
+            &keys,
+            self.values.as_ref(),
+        )?))
+    }
+}
+
+/// Concatenates the rows of `arrays`, which must all be of the same type, into
+/// `num_groups` arrays, routing each row to the array of the group given by the
+/// corresponding row of `groups`.
+///
+/// Rows keep their relative order within each group. Rows with a null group id
+/// are not part of any group, and an error is returned if a group id is not in
+/// `0..num_groups`, or if `groups` are not the same length as `arrays`.
+///
+/// ```
+/// use arrow::array::{Array, Int32Array};
+/// use arrow::compute::concat_partition;
+///
+/// let partitions = concat_partition(
+///     &[&Int32Array::from(vec![1, 2, 3]), &Int32Array::from(vec![4, 5])],
+///     &[&Int32Array::from(vec![0, 1, 0]), &Int32Array::from(vec![1, 1])],
+///     2,
+/// ).unwrap();
+/// assert_eq!(partitions[0].as_ref(), &Int32Array::from(vec![1, 3]) as &dyn Array);
+/// assert_eq!(partitions[1].as_ref(), &Int32Array::from(vec![2, 4, 5]) as &dyn Array);
+/// ```
+pub fn concat_partition(
+    arrays: &[&dyn Array],
+    groups: &[&PrimitiveArray<Int32Type>],
+    num_groups: usize,
+) -> Result<Vec<ArrayRef>> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+    if arrays.len() != groups.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "concat_partition requires one group array per array, got {} arrays and {} group arrays",
+            arrays.len(),
+            groups.len()
+        )));
+    }
+    if let Some((i, (array, groups))) = arrays
+        .iter()
+        .zip(groups)
+        .enumerate()
+        .find(|(_, (array, groups))| array.len() != groups.len())
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "groups[{}] has length {} but arrays[{}] has length {}",
+            i,
+            groups.len(),
+            i,
+            array.len()
+        )));
+    }
+
+    let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+    check_data_types(&arrays)?;
+
+    let mut capacities = vec![0; num_groups];
+    for group in groups.iter().flat_map(|groups| groups.iter().flatten()) {
+        match usize::try_from(group)
+            .ok()
+            .filter(|group| *group < num_groups)
+        {
+            Some(group) => capacities[group] += 1,
+            None => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "group id {} is out of bounds of {} groups",
+                    group, num_groups
+                )))
+            }
+        }
+    }
+
+    let mut partitions = capacities
+        .into_iter()
+        .map(|capacity| MutableArrayData::new(arrays.clone(), false, capacity))
+        .collect::<Vec<_>>();
+
+    for (i, groups) in groups.iter().enumerate() {
+        // extend by runs of consecutive rows of the same group
+        let mut start = 0;
+        for end in 1..=groups.len() {
+            if end == groups.len()
+                || groups.is_null(end) != groups.is_null(start)
+                || groups.value(end) != groups.value(start)
+            {
+                if groups.is_valid(start) {
+                    partitions[groups.value(start) as usize].extend(i, start, end);
+                }
+                start = end;
+            }
+        }
+    }
+
+    Ok(partitions
+        .into_iter()
+        .map(|partition| make_array(partition.freeze()))
+        .collect())
+}
+
+/// Groups `arrays` by [DataType] and concatenates the arrays of each group
+/// using [concat], returning one array per distinct type.
+///
+/// The results are in the order in which their type first appears in
+/// `arrays`, and the arrays of each group keep their relative order. An empty
+/// `arrays` returns no results.
+///
+/// ```
+/// use arrow::array::{Array, Int64Array, StringArray};
+/// use arrow::compute::concat_grouped_by_type;
+///
+/// let results = concat_grouped_by_type(&[
+///     &Int64Array::from(vec![1]),
+///     &StringArray::from(vec!["a"]),
+///     &Int64Array::from(vec![2]),
+/// ]).unwrap();
+/// assert_eq!(results.len(), 2);
+/// assert_eq!(results[0].as_ref(), &Int64Array::from(vec![1, 2]) as &dyn Array);
+/// assert_eq!(results[1].as_ref(), &StringArray::from(vec!["a"]) as &dyn Array);
+/// ```
+pub fn concat_grouped_by_type(arrays: &[&dyn Array]) -> Result<Vec<ArrayRef>> {
+    let mut groups: Vec<(&DataType, Vec<&dyn Array>)> = vec![];
+    for array in arrays {
+        match groups
+            .iter_mut()
+            .find(|(data_type, _)| *data_type == array.data_type())
+        {
+            Some((_, group)) => group.push(*array),
+            None => groups.push((array.data_type(), vec![*array])),
+        }
+    }
+
+    groups.iter().map(|(_, group)| concat(group)).collect()
+}
+
+/// Concatenates `batches` together into a single [RecordBatch].
+///
+/// Every batch must have exactly the schema `schema`, including the metadata
+/// of its fields, and thus their extension types; columns are concatenated one
+/// by one using [concat]. If `batches` is empty, an empty [RecordBatch] with
+/// `schema` is returned.
+///
+/// The merged dictionary values of the columns whose field is ordered, see
+/// [Field::dict_is_ordered](crate::datatypes::Field::dict_is_ordered), are
+/// sorted so that the result keeps them ordered.
+pub fn concat_batches(
+    schema: &SchemaRef,
+    batches: &[RecordBatch],
+) -> Result<RecordBatch> {
+    if batches.is_empty() {
+        return Ok(RecordBatch::new_empty(schema.clone()));
+    }
+    if let Some((i, _)) = batches
+        .iter()
+        .enumerate()
+        .find(|&(_, batch)| batch.schema() != *schema)
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "batches[{}] schema is different with argument schema.",
+            i
+        )));
+    }
+    concat_columns(schema, batches)
+}
+
+/// Concatenates `batches` together into a single [RecordBatch], allowing the
+/// nullability of their fields to differ.
+///
+/// The schema of the result is the schema of the first batch, with every field
+/// that is nullable in any of `batches` marked as nullable. The fields of all
+/// batches must otherwise have the same names, data types and extension types,
+/// as given by their `ARROW:extension:name` and `ARROW:extension:metadata`
+/// metadata, or an error is returned. Nullability is only unified for top
+/// level fields, the children of nested types are part of their [DataType] and
+/// must match.
+///
+/// Unlike [concat_batches], `batches` must not be empty, as the schema of the
+/// result is derived from them.
+pub fn concat_batches_unify_nullability(batches: &[RecordBatch]) -> Result<RecordBatch> {
+    if batches.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one batch".to_string(),
+        ));
+    }
+
+    let first = batches[0].schema();
+    let mut fields = first.fields().clone();
+    for (i, batch) in batches.iter().enumerate().skip(1) {
+        let schema = batch.schema();
+        let compatible = schema.fields().len() == fields.len()
+            && schema.fields().iter().zip(&fields).all(|(a, b)| {
+                a.name() == b.name()
+                    && a.data_type() == b.data_type()
+                    && extension_metadata(a) == extension_metadata(b)
+            });
+        if !compatible {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "batches[{}] schema is not compatible with batches[0] schema.",
+                i
+            )));
+        }
+        for (field, other) in fields.iter_mut().zip(schema.fields()) {
+            if other.is_nullable() {
+                field.set_nullable(true);
+            }
+        }
+    }
+
+    let schema = Arc::new(Schema::new_with_metadata(fields, first.metadata().clone()));
+    concat_columns(&schema, batches)
+}
+
+/// Concatenates `batches` together into a single [RecordBatch], allowing their
+/// schemas to have different columns.
+///
+/// The schema of the result has the union of the fields of `batches`, in the
+/// order they first appear in. Batches lacking a field get an all null column
+/// for it, and such fields are marked as nullable. Fields with the same name
+/// must have the same data type and extension type in all of `batches`, or an
+/// error is returned.
+/// The metadata of the result is that of the first batch.
+///
+/// Unlike [concat_batches], `batches` must not be empty, as the schema of the
+/// result is derived from them.
+///
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::{Array, Int32Array, StringArray};
+/// use arrow::compute::concat_batches_union_schema;
+/// use arrow::datatypes::{DataType, Field, Schema};
+/// use arrow::record_batch::RecordBatch;
+///
+/// let a = RecordBatch::try_new(
+///     Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)])),
+///     vec![Arc::new(Int32Array::from(vec![1, 2]))],
+/// ).unwrap();
+/// let b = RecordBatch::try_new(
+///     Arc::new(Schema::new(vec![
+///         Field::new("a", DataType::Int32, false),
+///         Field::new("b", DataType::Utf8, false),
+///     ])),
+///     vec![
+///         Arc::new(Int32Array::from(vec![3])),
+///         Arc::new(StringArray::from(vec!["c"])),
+///     ],
+/// ).unwrap();
+///
+/// let batch = concat_batches_union_schema(&[a, b]).unwrap();
+/// assert_eq!(batch.num_columns(), 2);
+/// assert_eq!(batch.column(1).null_count(), 2);
+/// ```
+pub fn concat_batches_union_schema(batches: &[RecordBatch]) -> Result<RecordBatch> {
+    if batches.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one batch".to_string(),
+        ));
+    }
+
+    let mut fields: Vec<Field> = vec![];
+    for (i, batch) in batches.iter().enumerate() {
+        let schema = batch.schema();
+        for other in schema.fields() {
+            match fields.iter_mut().find(|field| field.name() == other.name()) {
+                Some(field) if field.data_type() != other.data_type() => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "batches[{}] field {} has type {:?}, which conflicts with type {:?} of earlier batches.",
+                        i,
+                        other.name(),
+                        other.data_type(),
+                        field.data_type()
+                    )));
+                }
+                Some(field) if extension_metadata(field) != extension_metadata(other) => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "batches[{}] field {} has an extension type which conflicts with that of earlier batches.",
+                        i,
+                        other.name()
+                    )));
+                }
+                Some(field) => {
+                    if other.is_nullable() {
+                        field.set_nullable(true);
+                    }
+                }
+                None => fields.push(other.clone()),
+            }
+        }
+    }
+    for field in fields.iter_mut() {
+        if batches
+            .iter()
+            .any(|batch| batch.schema().column_with_name(field.name()).is_none())
+        {
+            field.set_nullable(true);
+        }
+    }
+
+    let schema = Arc::new(Schema::new_with_metadata(
+        fields,
+        batches[0].schema().metadata().clone(),
+    ));
+    let batches = batches
+        .iter()
+        .map(|batch| {
+            let columns = schema
+                .fields()
+                .iter()
+                .map(
+                    |field| match batch.schema().column_with_name(field.name()) {
+                        Some((i, _)) => batch.column(i).clone(),
+                        None => new_null_array(field.data_type(), batch.num_rows()),
+                    },
+                )
+                .collect();
+            RecordBatch::try_new(schema.clone(), columns)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    concat_columns(&schema, &batches)
+}
+
+/// Concatenates `arrays`, using [concat], into a [RecordBatch] with a single
+/// column named `field_name`.
+///
+/// The field of the column is nullable if the concatenated array has nulls.
+///
+/// ```
+/// use arrow::array::Int32Array;
+/// use arrow::compute::concat_to_batch;
+///
+/// let batch = concat_to_batch(
+///     &[&Int32Array::from(vec![1, 2]), &Int32Array::from(vec![Some(3), None])],
+///     "a",
+/// ).unwrap();
+/// assert_eq!(batch.num_rows(), 4);
+/// assert!(batch.schema().field(0).is_nullable());
+/// ```
+pub fn concat_to_batch(arrays: &[&dyn Array], field_name: &str) -> Result<RecordBatch> {
+    let array = concat(arrays)?;
+    let field = Field::new(
+        field_name,
+        array.data_type().clone(),
+        array.null_count() > 0,
+    );
+    RecordBatch::try_new(Arc::new(Schema::new(vec![field])), vec![array])
+}
+
+/// Returns the extension name and metadata of `field`, if any
+fn extension_metadata(field: &Field) -> [Option<&String>; 2] {
+    let metadata = field.metadata();
+    ["ARROW:extension:name", "ARROW:extension:metadata"]
+        .map(|key| metadata.and_then(|metadata| metadata.get(key)))
+}
+
+/// Concatenates the columns of `batches` into a [RecordBatch] with `schema`
+fn concat_columns(schema: &SchemaRef, batches: &[RecordBatch]) -> Result<RecordBatch> {
+    let field_num = schema.fields().len();
+    let mut arrays = Vec::with_capacity(field_num);
+    for i in 0..field_num {
+        let columns = batches
+            .iter()
+            .map(|batch| batch.column(i).as_ref())
+            .collect::<Vec<_>>();
+        let mut array = concat(&columns)?;
+
+        // only dictionaries are ordered, whatever the flag of other fields, and
+        // inputs sharing their dictionary values keep them as they are
+        let ordered = matches!(array.data_type(), DataType::Dictionary(_, _))
+            && schema.field(i).dict_is_ordered() == Some(true);
+        if ordered
+            && !columns
+                .windows(2)
+                .all(|a| a[0].data().child_data()[0].ptr_eq(&a[1].data().child_data()[0]))
+        {
+            array = sort_dictionary(&array)?;
+        }
+        arrays.push(array);
+    }
+    RecordBatch::try_new(schema.clone(), arrays)
+}
+
+/// Sorts the values of `array`, a dictionary array, remapping its keys to the
+/// sorted values
+fn sort_dictionary(array: &ArrayRef) -> Result<ArrayRef> {
+    let data = array.data();
+    let key_type = match data.data_type() {
+        DataType::Dictionary(key_type, _) => key_type.as_ref(),
+        // other types are not ordered
+        _ => return Ok(array.clone()),
+    };
+
+    let values = make_array(data.child_data()[0].clone());
+    let sort_indices = sort_to_indices(&values, None, None)?;
+    // the position of each value in the sorted values
+    let mut positions = vec![0; values.len()];
+    for (position, index) in sort_indices.values().iter().enumerate() {
+        positions[*index as usize] = position as u32;
+    }
+
+    let keys = ArrayData::builder(key_type.clone())
+        .len(data.len())
+        .offset(data.offset())
+        .null_bit_buffer(data.null_buffer().cloned())
+        .add_buffer(data.buffers()[0].clone())
+        .build()?;
+    let keys = cast(&make_array(keys), &DataType::UInt32)?;
+    let keys = keys.as_any().downcast_ref::<UInt32Array>().unwrap();
+    let keys = take(&UInt32Array::from(positions), keys, None)?;
+    let keys = cast(&keys, key_type)?;
+
+    let values = take(values.as_ref(), &sort_indices, None)?;
+    let data = ArrayData::builder(data.data_type().clone())
+        .len(keys.len())
+        .null_bit_buffer(keys.data().null_buffer().cloned())
+        .add_buffer(keys.data().buffers()[0].clone())
+        .add_child_data(values.data().clone())
+        .build()?;
+    Ok(make_array(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_concat_empty_vec() {
+        let re = concat(&[]);
+        assert!(re.is_err());
+    }
+
+    #[test]
+    fn test_concat_one_element_vec() -> Result<()> {
+        let arr = Arc::new(PrimitiveArray::<Int64Type>::from(vec![
+            Some(-1),
+            Some(2),
+            None,
+        ])) as ArrayRef;
+        let result = concat(&[arr.as_ref()])?;
+        assert_eq!(
+            &arr, &result,
+            "concatenating single element array gives back the same result"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_incompatible_datatypes() {
+        let re = concat(&[
+            &PrimitiveArray::<Int64Type>::from(vec![Some(-1), Some(2), None]),
+            &StringArray::from(vec![Some("hello"), Some("bar"), Some("world")]),
+        ]);
+        assert!(re.is_err());
+    }
+
+    #[test]
+    fn test_concat_incompatible_datatypes_index() {
+        let a = Int64Array::from(vec![1, 2]);
+        let b = StringArray::from(vec!["a"]);
+        let mut arrays = vec![&a as &dyn Array; 10];
+        arrays[7] = &b;
+        arrays[9] = &b;
+
+        let error = concat(&arrays).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: It is not possible to concatenate arrays of different data types: array 0 has type Int64 but array 7 has type Utf8"
+        );
+    }
+
+    #[test]
+    fn test_concat_string_arrays() -> Result<()> {
+        let arr = concat(&[
+            &StringArray::from(vec!["hello", "world"]),
+            &StringArray::from(vec!["2", "3", "4"]),
+            &StringArray::from(vec![Some("foo"), Some("bar"), None, Some("baz")]),
+        ])?;
+
+        let expected_output = Arc::new(StringArray::from(vec![
+            Some("hello"),
+            Some("world"),
+            Some("2"),
+            Some("3"),
+            Some("4"),
+            Some("foo"),
+            Some("bar"),
+            None,
+            Some("baz"),
+        ])) as ArrayRef;
+
+        assert_eq!(&arr, &expected_output);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_primitive_arrays() -> Result<()> {
+        let arr = concat(&[
+            &PrimitiveArray::<Int64Type>::from(vec![
+                Some(-1),
+                Some(-1),
+                Some(2),
+                None,
+                None,
+            ]),
+            &PrimitiveArray::<Int64Type>::from(vec![
+                Some(101),
+                Some(102),
+                Some(103),
+                None,
+            ]),
+            &PrimitiveArray::<Int64Type>::from(vec![Some(256), Some(512), Some(1024)]),
+        ])?;
+
+        let expected_output = Arc::new(PrimitiveArray::<Int64Type>::from(vec![
+            Some(-1),
+            Some(-1),
+            Some(2),
+            None,
+            None,
+            Some(101),
+            Some(102),
+            Some(103),
+            None,
+            Some(256),
+            Some(512),
+            Some(1024),
+        ])) as ArrayRef;
+
+        assert_eq!(&arr, &expected_output);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_primitive_array_slices() -> Result<()> {
+        let input_1 = PrimitiveArray::<Int64Type>::from(vec![
+            Some(-1),
+            Some(-1),
+            Some(2),
+            None,
+            None,
+        ])
+        .slice(1, 3);
+
+        let input_2 = PrimitiveArray::<Int64Type>::from(vec![
+            Some(101),
+            Some(102),
+            Some(103),
+            None,
+        ])
+        .slice(1, 3);
+        let arr = concat(&[input_1.as_ref(), input_2.as_ref()])?;
+
+        let expected_output = Arc::new(PrimitiveArray::<Int64Type>::from(vec![
+            Some(-1),
+            Some(2),
+            None,
+            Some(102),
+            Some(103),
+            None,
+        ])) as ArrayRef;
+
+        assert_eq!(&arr, &expected_output);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_boolean_primitive_arrays() -> Result<()> {
+        let arr = concat(&[
+            &BooleanArray::from(vec![
+                Some(true),
+                Some(true),
+                Some(false),
+                None,
+                None,
+                Some(false),
+            ]),
+            &BooleanArray::from(vec![None, Some(false), Some(true), Some(false)]),
+        ])?;
+
+        let expected_output = Arc::new(BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(false),
+            None,
+            None,
+            Some(false),
+            None,
+            Some(false),
+            Some(true),
+            Some(false),
+        ])) as ArrayRef;
+
+        assert_eq!(&arr, &expected_output);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_primitive_list_arrays() -> Result<()> {
+        let list1 = vec![
+            Some(vec![Some(-1), Some(-1), Some(2), None, None]),
+            Some(vec![]),
+            None,
+            Some(vec![Some(10)]),
+        ];
+        let list1_array =
+            ListArray::from_iter_primitive::<Int64Type, _, _>(list1.clone());
+
+        let list2 = vec![
+            None,
+            Some(vec![Some(100), None, Some(101)]),
+            Some(vec![Some(102)]),
+        ];
+        let list2_array =
+            ListArray::from_iter_primitive::<Int64Type, _, _>(list2.clone());
+
+        let list3 = vec![Some(vec![Some(1000), Some(1001)])];
+        let list3_array =
+            ListArray::from_iter_primitive::<Int64Type, _, _>(list3.clone());
+
+        let array_result = concat(&[&list1_array, &list2_array, &list3_array])?;
+
+        let expected = list1
+            .into_iter()
+            .chain(list2.into_iter())
+            .chain(list3.into_iter());
+        let array_expected = ListArray::from_iter_primitive::<Int64Type, _, _>(expected);
+
+        assert_eq!(array_result.as_ref(), &array_expected as &dyn Array);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_struct_arrays() -> Result<()> {
+        let field = Field::new("field", DataType::Int64, true);
+        let input_primitive_1: ArrayRef =
+            Arc::new(PrimitiveArray::<Int64Type>::from(vec![
+                Some(-1),
+                Some(-1),
+                Some(2),
+                None,
+                None,
+            ]));
+        let input_struct_1 = StructArray::from(vec![(field.clone(), input_primitive_1)]);
+
+        let input_primitive_2: ArrayRef =
+            Arc::new(PrimitiveArray::<Int64Type>::from(vec![
+                Some(101),
+                Some(102),
+                Some(103),
+                None,
+            ]));
+        let input_struct_2 = StructArray::from(vec![(field.clone(), input_primitive_2)]);
+
+        let input_primitive_3: ArrayRef =
+            Arc::new(PrimitiveArray::<Int64Type>::from(vec![
+                Some(256),
+                Some(512),
+                Some(1024),
+            ]));
+        let input_struct_3 = StructArray::from(vec![(field, input_primitive_3)]);
+
+        let arr = concat(&[&input_struct_1, &input_struct_2, &input_struct_3])?;
+
+        let expected_primitive_output = Arc::new(PrimitiveArray::<Int64Type>::from(vec![
+            Some(-1),
+            Some(-1),
+            Some(2),
+            None,
+            None,
+            Some(101),
+            Some(102),
+            Some(103),
+            None,
+            Some(256),
+            Some(512),
+            Some(1024),
+        ])) as ArrayRef;
+
+        let actual_primitive = arr
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap()
+            .column(0);
+        assert_eq!(actual_primitive, &expected_primitive_output);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_struct_array_slices() -> Result<()> {
+        let field = Field::new("field", DataType::Int64, true);
+        let input_primitive_1: ArrayRef =
+            Arc::new(PrimitiveArray::<Int64Type>::from(vec![
+                Some(-1),
+                Some(-1),
+                Some(2),
+                None,
+                None,
+            ]));
+        let input_struct_1 = StructArray::from(vec![(field.clone(), input_primitive_1)]);
+
+        let input_primitive_2: ArrayRef =
+            Arc::new(PrimitiveArray::<Int64Type>::from(vec![
+                Some(101),
+                Some(102),
+                Some(103),
+                None,
+            ]));
+        let input_struct_2 = StructArray::from(vec![(field, input_primitive_2)]);
+
+        let arr = concat(&[
+            input_struct_1.slice(1, 3).as_ref(),
+            input_struct_2.slice(1, 2).as_ref(),
+        ])?;
+
+        let expected_primitive_output = Arc::new(PrimitiveArray::<Int64Type>::from(vec![
+            Some(-1),
+            Some(2),
+            None,
+            Some(102),
+            Some(103),
+        ])) as ArrayRef;
+
+        let actual_primitive = arr
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap()
+            .column(0);
+        assert_eq!(actual_primitive, &expected_primitive_output);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_array_slices() -> Result<()> {
+        let input_1 = StringArray::from(vec!["hello", "A", "B", "C"]);
+        let input_2 = StringArray::from(vec!["world", "D", "E", "Z"]);
+
+        let arr = concat(&[input_1.slice(1, 3).as_ref(), input_2.slice(1, 2).as_ref()])?;
+
+        let expected_output = StringArray::from(vec!["A", "B", "C", "D", "E"]);
+
+        let actual_output = arr.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(actual_output, &expected_output);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_array_with_null_slices() -> Result<()> {
+        let input_1 = StringArray::from(vec![Some("hello"), None, Some("A"), Some("C")]);
+        let input_2 = StringArray::from(vec![None, Some("world"), Some("D"), None]);
+
+        let arr = concat(&[input_1.slice(1, 3).as_ref(), input_2.slice(1, 2).as_ref()])?;
+
+        let expected_output =
+            StringArray::from(vec![None, Some("A"), Some("C"), Some("world"), Some("D")]);
+
+        let actual_output = arr.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(actual_output, &expected_output);
+
+        Ok(())
+    }
+
+    fn collect_string_dictionary(
+        dictionary: &DictionaryArray<Int32Type>,
+    ) -> Vec<Option<String>> {
+        let values = dictionary.values();
+        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+
+        dictionary
+            .keys()
+            .iter()
+            .map(|key| key.map(|key| values.value(key as _).to_string()))
+            .collect()
+    }
+
+    fn concat_dictionary(
+        input_1: DictionaryArray<Int32Type>,
+        input_2: DictionaryArray<Int32Type>,
+    ) -> Vec<Option<String>> {
+        let concat = concat(&[&input_1 as _, &input_2 as _]).unwrap();
+        let concat = concat
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int32Type>>()
+            .unwrap();
+
+        collect_string_dictionary(concat)
+    }
+
+    #[test]
+    fn test_string_dictionary_array() {
+        let input_1: DictionaryArray<Int32Type> =
+            vec!["hello", "A", "B", "hello", "hello", "C"]
+                .into_iter()
+                .collect();
+        let input_2: DictionaryArray<Int32Type> =
+            vec!["hello", "E", "E", "hello", "F", "E"]
+                .into_iter()
+                .collect();
+
+        let expected: Vec<_> = vec![
+            "hello", "A", "B", "hello", "hello", "C", "hello", "E", "E", "hello", "F",
+            "E",
+        ]
+        .into_iter()
+        .map(|x| Some(x.to_string()))
+        .collect();
+
+        let concat = concat_dictionary(input_1, input_2);
+        assert_eq!(concat, expected);
+    }
+
+    #[test]
+    fn test_string_dictionary_array_nulls() {
+        let input_1: DictionaryArray<Int32Type> =
+            vec![Some("foo"), Some("bar"), None, Some("fiz")]
+                .into_iter()
+                .collect();
+        let input_2: DictionaryArray<Int32Type> = vec![None].into_iter().collect();
+        let expected = vec![
+            Some("foo".to_string()),
+            Some("bar".to_string()),
+            None,
+            Some("fiz".to_string()),
+            None,
+        ];
+
+        let concat = concat_dictionary(input_1, input_2);
+        assert_eq!(concat, expected);
+    }
+
+    #[test]
+    fn test_concat_string_sizes() -> Result<()> {
+        let a: LargeStringArray = ((0..150).map(|_| Some("foo"))).collect();
+        let b: LargeStringArray = ((0..150).map(|_| Some("foo"))).collect();
+        let c = LargeStringArray::from(vec![Some("foo"), Some("bar"), None, Some("baz")]);
+        // 150 * 3 = 450
+        // 150 * 3 = 450
+        // 3 * 3   = 9
+        // ------------+
+        // 909
+        // closest 64 byte aligned cap = 960
+
+        let arr = concat(&[&a, &b, &c])?;
+        // this would have been 1280 if we did not precompute the value lengths.
+        assert_eq!(arr.data().buffers()[1].capacity(), 960);
+        assert_eq!(arr.data().buffers()[1].len(), 909);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_dictionary_sizes() -> Result<()> {
+        let a: DictionaryArray<Int32Type> = (0..150).map(|_| "foo").collect();
+        let b: DictionaryArray<Int32Type> =
+            (0..150).map(|i| ["bar", "baz"][i % 2]).collect();
+        // 3 + 3 + 3 = 9 value bytes, closest 64 byte aligned cap = 64
+
+        let arr = concat(&[&a, &b])?;
+        assert_eq!(arr.len(), 300);
+        assert_eq!(arr.data().child_data()[0].len(), 3);
+        assert_eq!(arr.data().child_data()[0].buffers()[1].capacity(), 64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_extension_sizes() -> Result<()> {
+        let metadata = BTreeMap::from([(
+            "ARROW:extension:name".to_string(),
+            "arrow.uuid".to_string(),
+        )]);
+        let mut field = Field::new("a", DataType::Utf8, false);
+        field.set_metadata(Some(metadata));
+        let schema = Arc::new(Schema::new(vec![field]));
+
+        let a: StringArray = ((0..150).map(|_| Some("foo"))).collect();
+        let b: StringArray = ((0..150).map(|_| Some("foo"))).collect();
+        let batch1 = RecordBatch::try_new(schema.clone(), vec![Arc::new(a)])?;
+        let batch2 = RecordBatch::try_new(schema.clone(), vec![Arc::new(b)])?;
+
+        // 150 * 3 + 150 * 3 = 900, closest 64 byte aligned cap = 960
+        let batch = concat_batches(&schema, &[batch1, batch2])?;
+        assert_eq!(batch.schema(), schema);
+        assert_eq!(batch.column(0).data().buffers()[1].capacity(), 960);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_binary_sizes() -> Result<()> {
+        let a: BinaryArray = ((0..150).map(|_| Some(b"foo" as &[u8]))).collect();
+        let b: BinaryArray = ((0..150).map(|_| Some(b"foo" as &[u8]))).collect();
+        let c = BinaryArray::from_opt_vec(vec![
+            Some(&b"foo"[..]),
+            Some(&b"bar"[..]),
+            None,
+            Some(&b"baz"[..]),
+        ]);
+        // 150 * 3 + 150 * 3 + 3 * 3 = 909
+        // closest 64 byte aligned cap = 960
+
+        let arr = concat(&[&a, &b, &c])?;
+        assert_eq!(arr.data().buffers()[1].capacity(), 960);
+
+        let a: LargeBinaryArray = ((0..150).map(|_| Some(b"foo" as &[u8]))).collect();
+        let b = LargeBinaryArray::from_opt_vec(vec![Some(&b"bar"[..]), None]);
+
+        // 150 * 3 + 3 = 453, closest 64 byte aligned cap = 512
+        let arr = concat(&[&a, &b])?;
+        assert_eq!(arr.data().buffers()[1].capacity(), 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_fixed_size_binary_sizes() -> Result<()> {
+        let a = FixedSizeBinaryArray::try_from_iter((0..10).map(|i| [i as u8; 16]))?;
+        let b = FixedSizeBinaryArray::try_from_iter((0..20).map(|i| [i as u8; 16]))?;
+        let c = FixedSizeBinaryArray::try_from_sparse_iter(
+            (0..30).map(|i| (i % 3 != 0).then(|| [i as u8; 16])),
+        )?;
+        // (10 + 20 + 30) * 16 = 960, already 64 byte aligned
+
+        let arr = concat(&[&a, &b, &c])?;
+        assert_eq!(arr.len(), 60);
+        assert_eq!(arr.null_count(), 10);
+        // the value buffer is sized in bytes, not in elements
+        assert_eq!(arr.data().buffers()[0].capacity(), 960);
+
+        let arr = arr.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+        assert_eq!(arr.value(0), &[0; 16]);
+        assert_eq!(arr.value(29), &[19; 16]);
+        assert!(arr.is_null(30));
+        assert_eq!(arr.value(59), &[29; 16]);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(not(feature = "force_validate"))]
+    fn test_concat_string_offset_overflow() {
+        // zeroed value buffers are lazily allocated, so this is cheap
+        let string_stub = |len: usize| {
+            let values = Buffer::from(MutableBuffer::from_len_zeroed(len));
+            let offsets = Buffer::from_slice_ref(&[0, len as i32]);
+            let data = unsafe {
+                ArrayData::builder(DataType::Utf8)
+                    .len(1)
+                    .add_buffer(offsets)
+                    .add_buffer(values)
+                    .build_unchecked()
+            };
+            StringArray::from(data)
+        };
+        let large = string_stub(1 << 30);
+        let smaller = string_stub((1 << 30) - 1);
+
+        // 2^30 + 2^30 - 1 = i32::MAX value bytes still fit
+        let values_size =
+            compute_binary_values_length::<i32>(&[large.data(), smaller.data()]);
+        assert_eq!(values_size, i32::MAX as usize);
+
+        let error = concat(&[&large, &large]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Compute error: concat of Utf8 arrays with 2147483648 value bytes would overflow i32 offsets, consider casting to LargeUtf8 first"
+        );
+    }
+
+    #[test]
+    fn test_dictionary_concat_reuse() {
+        let array: DictionaryArray<Int8Type> =
+            vec!["a", "a", "b", "c"].into_iter().collect();
+        let copy: DictionaryArray<Int8Type> = array.data().clone().into();
+
+        // dictionary is "a", "b", "c"
+        assert_eq!(
+            array.values(),
+            &(Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef)
+        );
+        assert_eq!(array.keys(), &Int8Array::from(vec![0, 0, 1, 2]));
+
+        // concatenate it with itself
+        let combined = concat(&[&copy as _, &array as _]).unwrap();
+
+        let combined = combined
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+
+        assert_eq!(
+            combined.values(),
+            &(Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef),
+            "Actual: {:#?}",
+            combined
+        );
+
+        assert_eq!(
+            combined.keys(),
+            &Int8Array::from(vec![0, 0, 1, 2, 0, 0, 1, 2])
+        );
+
+        // Should have reused the dictionary
+        assert!(array.data().child_data()[0].ptr_eq(&combined.data().child_data()[0]));
+        assert!(copy.data().child_data()[0].ptr_eq(&combined.data().child_data()[0]));
+
+        let new: DictionaryArray<Int8Type> = vec!["d"].into_iter().collect();
+        let combined = concat(&[&copy as _, &array as _, &new as _]).unwrap();
+
+        // Should not have reused the dictionary
+        assert!(!array.data().child_data()[0].ptr_eq(&combined.data().child_data()[0]));
+        assert!(!copy.data().child_data()[0].ptr_eq(&combined.data().child_data()[0]));
+        assert!(!new.data().child_data()[0].ptr_eq(&combined.data().child_data()[0]));
+    }
+
+    #[test]
+    fn test_concat_batches() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let batch1 = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None])),
+                Arc::new(StringArray::from(vec!["a", "b"])),
+            ],
+        )?;
+        let batch2 = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![3, 4, 5])),
+                Arc::new(StringArray::from(vec![Some("c"), None, Some("e")])),
+            ],
+        )?;
+
+        let batch = concat_batches(&schema, &[batch1, batch2])?;
+        assert_eq!(batch.schema(), schema);
+        assert_eq!(batch.num_rows(), 5);
+        assert_eq!(
+            batch.column(0).as_ref(),
+            &Int32Array::from(vec![Some(1), None, Some(3), Some(4), Some(5)])
+                as &dyn Array
+        );
+        assert_eq!(
+            batch.column(1).as_ref(),
+            &StringArray::from(vec![Some("a"), Some("b"), Some("c"), None, Some("e")])
+                as &dyn Array
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_batches_unify_nullability() -> Result<()> {
+        let schema1 = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let schema2 = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let batch1 = RecordBatch::try_new(
+            schema1,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec!["a", "b"])),
+            ],
+        )?;
+        let batch2 = RecordBatch::try_new(
+            schema2.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![None, Some(4)])),
+                Arc::new(StringArray::from(vec!["c", "d"])),
+            ],
+        )?;
+
+        let batch = concat_batches_unify_nullability(&[batch1.clone(), batch2])?;
+        assert_eq!(batch.schema(), schema2);
+        assert!(batch.schema().field(0).is_nullable());
+        assert!(!batch.schema().field(1).is_nullable());
+        assert_eq!(
+            batch.column(0).as_ref(),
+            &Int32Array::from(vec![Some(1), Some(2), None, Some(4)]) as &dyn Array
+        );
+
+        let schema3 = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let batch3 = RecordBatch::try_new(
+            schema3,
+            vec![
+                Arc::new(Int64Array::from(vec![5])),
+                Arc::new(StringArray::from(vec!["e"])),
+            ],
+        )?;
+        let error = concat_batches_unify_nullability(&[batch1, batch3]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: batches[1] schema is not compatible with batches[0] schema."
+        );
+
+        assert!(concat_batches_unify_nullability(&[]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_batches_union_schema() -> Result<()> {
+        let schema1 = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let schema2 = Arc::new(Schema::new(vec![
+            Field::new("b", DataType::Utf8, false),
+            Field::new("a", DataType::Int32, false),
+            Field::new("c", DataType::Float64, true),
+        ]));
+        let batch1 = RecordBatch::try_new(
+            schema1,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec!["a", "b"])),
+            ],
+        )?;
+        let batch2 = RecordBatch::try_new(
+            schema2,
+            vec![
+                Arc::new(StringArray::from(vec!["c"])),
+                Arc::new(Int32Array::from(vec![3])),
+                Arc::new(Float64Array::from(vec![4.5])),
+            ],
+        )?;
+
+        let batch = concat_batches_union_schema(&[batch1.clone(), batch2])?;
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+            Field::new("c", DataType::Float64, true),
+        ]);
+        assert_eq!(batch.schema().as_ref(), &expected);
+        assert_eq!(
+            batch.column(0).as_ref(),
+            &Int32Array::from(vec![1, 2, 3]) as &dyn Array
+        );
+        assert_eq!(
+            batch.column(1).as_ref(),
+            &StringArray::from(vec!["a", "b", "c"]) as &dyn Array
+        );
+        assert_eq!(
+            batch.column(2).as_ref(),
+            &Float64Array::from(vec![None, None, Some(4.5)]) as &dyn Array
+        );
+
+        // a field missing from later batches is made nullable
+        let batch3 = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("b", DataType::Utf8, false)])),
+            vec![Arc::new(StringArray::from(vec!["d"]))],
+        )?;
+        let batch = concat_batches_union_schema(&[batch1.clone(), batch3])?;
+        assert!(batch.schema().field(0).is_nullable());
+        assert!(!batch.schema().field(1).is_nullable());
+        assert_eq!(
+            batch.column(0).as_ref(),
+            &Int32Array::from(vec![Some(1), Some(2), None]) as &dyn Array
+        );
+
+        let batch4 = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)])),
+            vec![Arc::new(Int64Array::from(vec![5]))],
+        )?;
+        let error = concat_batches_union_schema(&[batch1, batch4]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: batches[1] field a has type Int64, which conflicts with type Int32 of earlier batches."
+        );
+
+        assert!(concat_batches_union_schema(&[]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_batches_empty() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let batch = concat_batches(&schema, &[])?;
+        assert_eq!(batch.schema(), schema);
+        assert_eq!(batch.num_columns(), 2);
+        assert_eq!(batch.num_rows(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_batches_different_schemas() {
+        let schema1 =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let schema2 =
+            Arc::new(Schema::new(vec![Field::new("b", DataType::Int32, false)]));
+        let batch1 = RecordBatch::try_new(
+            schema1.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2]))],
+        )
+        .unwrap();
+        let batch2 =
+            RecordBatch::try_new(schema2, vec![Arc::new(Int32Array::from(vec![3, 4]))])
+                .unwrap();
+
+        let error = concat_batches(&schema1, &[batch1, batch2]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: batches[1] schema is different with argument schema.",
+        );
+    }
+
+    #[test]
+    fn test_concat_builder() -> Result<()> {
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(vec![Some("hello"), None])),
+            Arc::new(StringArray::from(vec!["world"]).slice(0, 1)),
+            Arc::new(StringArray::from(vec![Some("a"), Some("b"), None]).slice(1, 2)),
+        ];
+
+        let mut builder = ConcatBuilder::new(DataType::Utf8);
+        assert!(builder.is_empty());
+        for array in &arrays {
+            builder.push(array.as_ref())?;
+        }
+        assert_eq!(builder.len(), 5);
+
+        let expected = concat(&arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>())?;
+        assert_eq!(&builder.finish()?, &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_builder_empty() -> Result<()> {
+        let builder = ConcatBuilder::with_capacity(DataType::Int64, 4);
+        let array = builder.finish()?;
+        assert_eq!(array.data_type(), &DataType::Int64);
+        assert!(array.is_empty());
+
+        let mut builder = ConcatBuilder::new(DataType::Int64);
+        let array = Int64Array::from(vec![1, 2]);
+        builder.push(&array)?;
+        builder.push(&Int64Array::from(Vec::<i64>::new()))?;
+        assert_eq!(builder.finish()?.as_ref(), &array as &dyn Array);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_builder_copies_pushed_arrays() -> Result<()> {
+        let mut builder = ConcatBuilder::new(DataType::Int32);
+        // the pushed arrays are dropped right away, as their values are copied
+        builder.push(&Int32Array::from(vec![1, 2]))?;
+        builder.push(
+            Int32Array::from(vec![Some(3), None, Some(5)])
+                .slice(1, 2)
+                .as_ref(),
+        )?;
+        builder.push(&Int32Array::from(vec![6]))?;
+        let array = builder.finish()?;
+        assert_eq!(
+            array.as_ref(),
+            &Int32Array::from(vec![Some(1), Some(2), None, Some(5), Some(6)])
+                as &dyn Array
+        );
+
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(BooleanArray::from(vec![true, false, true])),
+            Arc::new(BooleanArray::from(vec![Some(false), None, Some(true)]).slice(1, 2)),
+            Arc::new(BooleanArray::from(vec![true; 70])),
+        ];
+        let mut builder = ConcatBuilder::with_capacity(DataType::Boolean, 75);
+        for array in &arrays {
+            builder.push(array.as_ref())?;
+        }
+        let expected = concat(&arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>())?;
+        assert_eq!(builder.finish()?.as_ref(), expected.as_ref());
+
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(LargeBinaryArray::from(vec![b"ab".as_ref(), b"c"]).slice(1, 1)),
+            Arc::new(LargeBinaryArray::from_opt_vec(vec![None, Some(b"def")])),
+        ];
+        let mut builder = ConcatBuilder::new(DataType::LargeBinary);
+        for array in &arrays {
+            builder.push(array.as_ref())?;
+        }
+        let expected = concat(&arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>())?;
+        assert_eq!(builder.finish()?.as_ref(), expected.as_ref());
+
+        // nested types are concatenated when finished
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+        ]);
+        let mut builder = ConcatBuilder::new(list.data_type().clone());
+        builder.push(&list)?;
+        builder.push(&list)?;
+        assert_eq!(builder.len(), 4);
+        assert_eq!(
+            builder.finish()?.as_ref(),
+            concat(&[&list, &list])?.as_ref()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(not(feature = "force_validate"))]
+    fn test_concat_builder_offset_overflow() {
+        // zeroed value buffers are lazily allocated, so this is cheap
+        let values = Buffer::from(MutableBuffer::from_len_zeroed(1 << 30));
+        let data = unsafe {
+            ArrayData::builder(DataType::Utf8)
+                .len(1)
+                .add_buffer(Buffer::from_slice_ref(&[0, 1 << 30]))
+                .add_buffer(values)
+                .build_unchecked()
+        };
+        let array = StringArray::from(data);
+
+        let mut builder = ConcatBuilder::new(DataType::Utf8);
+        builder.push(&array).unwrap();
+        let error = builder.push(&array).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Compute error: concat of Utf8 arrays with 2147483648 value bytes would overflow i32 offsets, consider casting to LargeUtf8 first"
+        );
+    }
+
+    #[test]
+    fn test_concat_builder_incompatible_datatypes() {
+        let mut builder = ConcatBuilder::new(DataType::Int64);
+        builder.push(&Int64Array::from(vec![1, 2])).unwrap();
+        let error = builder.push(&StringArray::from(vec!["a"])).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: It is not possible to concatenate arrays of different data types: expected Int64, got Utf8"
+        );
+    }
+
+    #[test]
+    fn test_dictionary_concat_shared_values() {
+        let array: DictionaryArray<Int16Type> =
+            vec![Some("a"), None, Some("b"), Some("a")]
+                .into_iter()
+                .collect();
+        let copy1: DictionaryArray<Int16Type> = array.data().clone().into();
+        let copy2: DictionaryArray<Int16Type> = array.slice(1, 2).data().clone().into();
+
+        let combined = concat(&[&array as _, &copy1 as _, &copy2 as _]).unwrap();
+        let combined = combined
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int16Type>>()
+            .unwrap();
+
+        // the values are neither rebuilt nor duplicated, only the keys are appended
+        assert!(array.data().child_data()[0].ptr_eq(&combined.data().child_data()[0]));
+        assert_eq!(
+            combined.values(),
+            &(Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef)
+        );
+        assert_eq!(
+            combined.keys(),
+            &Int16Array::from(vec![
+                Some(0),
+                None,
+                Some(1),
+                Some(0),
+                Some(0),
+                None,
+                Some(1),
+                Some(0),
+                None,
+                Some(1)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_dictionary_concat_distinct_values() {
+        let input_1: DictionaryArray<Int8Type> =
+            vec!["a", "b", "a"].into_iter().collect();
+        let input_2: DictionaryArray<Int8Type> = vec!["b", "c"].into_iter().collect();
+
+        let combined = concat(&[&input_1 as _, &input_2 as _]).unwrap();
+        let combined = combined
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+
+        // values are concatenated without deduplication, keys are offset
+        assert_eq!(
+            combined.values(),
+            &(Arc::new(StringArray::from(vec!["a", "b", "b", "c"])) as ArrayRef)
+        );
+        assert_eq!(combined.keys(), &Int8Array::from(vec![0, 1, 0, 2, 3]));
+    }
+
+    #[test]
+    fn test_dictionary_concat_overlapping_values() {
+        let values = (0..110).map(|i| i.to_string()).collect::<Vec<_>>();
+        // the dictionaries share 90 of their 100 values
+        let input_1: DictionaryArray<Int16Type> =
+            values[..100].iter().map(|s| s.as_str()).collect();
+        let input_2: DictionaryArray<Int16Type> =
+            values[10..].iter().rev().map(|s| s.as_str()).collect();
+
+        let result = concat(&[&input_1 as _, &input_2 as _]).unwrap();
+        let combined = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int16Type>>()
+            .unwrap();
+
+        // the values are appended as-is and only the keys of input_2 are offset,
+        // neither the values nor the keys are hashed
+        assert_eq!(combined.values().len(), 200);
+        let keys = combined.keys().values();
+        assert_eq!(&keys[..100], input_1.keys().values());
+        assert!(keys[100..]
+            .iter()
+            .zip(input_2.keys().values())
+            .all(|(combined, key)| *combined == key + 100));
+
+        let combined = cast(&result, &DataType::Utf8);
+        let expected = values[..100]
+            .iter()
+            .chain(values[10..].iter().rev())
+            .map(|s| Some(s.as_str()))
+            .collect::<StringArray>();
+        assert_eq!(combined.unwrap().as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_concat_n() -> Result<()> {
+        let array = Int64Array::from(vec![Some(1), None, Some(3)]);
+
+        let result = concat_n(&array, 1000)?;
+        assert_eq!(result.len(), 3000);
+        assert_eq!(result.null_count(), 1000);
+        let expected = concat(&vec![&array as &dyn Array; 1000])?;
+        assert_eq!(&result, &expected);
+
+        let result = result.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(result.value(2997), 1);
+        assert!(result.is_null(2998));
+        assert_eq!(result.value(2999), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_n_strings() -> Result<()> {
+        let array = StringArray::from(vec![Some("foo"), None, Some("ba")]);
+        let sliced = array.slice(1, 2);
+
+        let result = concat_n(sliced.as_ref(), 3)?;
+        let expected =
+            StringArray::from(vec![None, Some("ba"), None, Some("ba"), None, Some("ba")]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+        // 3 * 2 bytes, tightly allocated
+        assert_eq!(result.data().buffers()[1].capacity(), 64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_n_zero_and_one() -> Result<()> {
+        let array = Int64Array::from(vec![1, 2, 3]);
+
+        let result = concat_n(&array, 0)?;
+        assert_eq!(result.data_type(), &DataType::Int64);
+        assert!(result.is_empty());
+
+        let result = concat_n(&array, 1)?;
+        assert!(result.data().ptr_eq(array.data()));
+
+        Ok(())
+    }
+
+    fn create_decimal_array(
+        array: &[Option<i128>],
+        precision: usize,
+        scale: usize,
+    ) -> DecimalArray {
+        array
+            .iter()
+            .collect::<DecimalArray>()
+            .with_precision_and_scale(precision, scale)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_concat_decimal_arrays() -> Result<()> {
+        let input_1 = create_decimal_array(&[Some(1), None, Some(123)], 10, 2);
+        let input_2 = create_decimal_array(&[Some(-5), Some(42)], 10, 2);
+
+        let arr = concat(&[&input_1, &input_2])?;
+
+        let expected =
+            create_decimal_array(&[Some(1), None, Some(123), Some(-5), Some(42)], 10, 2);
+        assert_eq!(arr.as_ref(), &expected as &dyn Array);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_decimal_arrays_different_precision() {
+        let input_1 = create_decimal_array(&[Some(1), None], 10, 2);
+        let input_2 = create_decimal_array(&[Some(1), None], 12, 2);
+
+        let error = concat(&[&input_1, &input_2]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: It is not possible to concatenate decimal arrays with different precision or scale: Decimal(10, 2) and Decimal(12, 2)"
+        );
+
+        let input_3 = create_decimal_array(&[Some(1), None], 10, 3);
+        let error = concat(&[&input_1, &input_1, &input_3]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: It is not possible to concatenate decimal arrays with different precision or scale: Decimal(10, 2) and Decimal(10, 3)"
+        );
+    }
+
+    #[test]
+    fn test_concat_decimals_rescale() -> Result<()> {
+        let input_1 = create_decimal_array(&[Some(12345), None, Some(-100)], 10, 2);
+        let input_2 = create_decimal_array(&[Some(1), Some(-99999)], 10, 4);
+
+        let array = concat_decimals_rescale(&[&input_1, &input_2], 4, false)?;
+        let expected = create_decimal_array(
+            &[Some(1234500), None, Some(-10000), Some(1), Some(-99999)],
+            12,
+            4,
+        );
+        assert_eq!(array.as_ref(), &expected as &dyn Array);
+
+        // scaling down only succeeds without dropping digits
+        let input_3 = create_decimal_array(&[Some(500), Some(12300)], 10, 4);
+        let array = concat_decimals_rescale(&[&input_1, &input_3], 2, false)?;
+        let expected = create_decimal_array(
+            &[Some(12345), None, Some(-100), Some(5), Some(123)],
+            10,
+            2,
+        );
+        assert_eq!(array.as_ref(), &expected as &dyn Array);
+
+        let error = concat_decimals_rescale(&[&input_1, &input_2], 2, false).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Compute error: rescaling 0.0001 to scale 2 would truncate it"
+        );
+        let array = concat_decimals_rescale(&[&input_1, &input_2], 2, true)?;
+        let expected = create_decimal_array(
+            &[Some(12345), None, Some(-100), Some(0), Some(-999)],
+            10,
+            2,
+        );
+        assert_eq!(array.as_ref(), &expected as &dyn Array);
+
+        let wide = create_decimal_array(&[Some(1)], 38, 0);
+        let error = concat_decimals_rescale(&[&wide, &input_1], 2, false).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: precision 40 is greater than max 38"
+        );
+
+        let error =
+            concat_decimals_rescale(&[&input_1, &Int32Array::from(vec![1])], 2, false)
+                .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: concat_decimals_rescale requires decimal arrays, got Int32"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_sparse_nulls() {
+        let sparse = (0..1000)
+            .map(|i| (i % 20 == 7).then(|| i))
+            .collect::<Int64Array>();
+        let sliced = sparse.slice(3, 500);
+        let dense = Int64Array::from(vec![Some(1), None, Some(3)]);
+        let inputs = [sparse.data(), sliced.data(), dense.data()];
+
+        let array = concat(&[&sparse, sliced.as_ref(), &dense]).unwrap();
+        assert!(array.null_count() * 100 >= array.len() * SPARSE_NULL_PERCENT);
+
+        // the generic path
+        let mut mutable = MutableArrayData::new(inputs.to_vec(), false, 0);
+        for (i, input) in inputs.iter().enumerate() {
+            mutable.extend(i, 0, input.len());
+        }
+        let expected = make_array(mutable.freeze());
+        assert_eq!(array.as_ref(), expected.as_ref());
+        assert_eq!(array.null_count(), expected.null_count());
+    }
+
+    type MapEntries<K> = Option<Vec<(K, Option<i32>)>>;
+
+    fn create_map_array(entries: &[MapEntries<&str>]) -> MapArray {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(0), Int32Builder::new(0));
+        for entry in entries {
+            if let Some(entry) = entry {
+                for (key, value) in entry {
+                    builder.keys().append_value(key).unwrap();
+                    builder.values().append_option(*value).unwrap();
+                }
+            }
+            builder.append(entry.is_some()).unwrap();
+        }
+        builder.finish()
+    }
+
+    fn collect_map_array(map: &MapArray) -> Vec<MapEntries<String>> {
+        (0..map.len())
+            .map(|i| {
+                map.is_valid(i).then(|| {
+                    let entries = map.value(i);
+                    let entries = entries.as_any().downcast_ref::<StructArray>().unwrap();
+                    let keys = as_string_array(entries.column(0));
+                    let values = as_primitive_array::<Int32Type>(entries.column(1));
+                    (0..entries.len())
+                        .map(|j| {
+                            (
+                                keys.value(j).to_string(),
+                                values.is_valid(j).then(|| values.value(j)),
+                            )
+                        })
+                        .collect()
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_concat_map_arrays() -> Result<()> {
+        let input_1 = vec![Some(vec![("a", Some(1)), ("b", None)]), None, Some(vec![])];
+        let input_2 = vec![
+            Some(vec![("c", Some(3))]),
+            Some(vec![("d", Some(4)), ("e", Some(5))]),
+        ];
+        let map_1 = create_map_array(&input_1);
+        let map_2 = create_map_array(&input_2);
+
+        let arr = concat(&[&map_1, &map_2])?;
+        assert_eq!(arr.data_type(), map_1.data_type());
+        let arr = arr.as_any().downcast_ref::<MapArray>().unwrap();
+
+        let expected = input_1
+            .iter()
+            .chain(input_2.iter())
+            .map(|entry| {
+                entry
+                    .as_ref()
+                    .map(|entry| entry.iter().map(|(k, v)| (k.to_string(), *v)).collect())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(collect_map_array(arr), expected);
+        assert_eq!(arr.keys().len(), 5);
+
+        // sliced inputs
+        let arr = concat(&[map_1.slice(1, 2).as_ref(), map_2.slice(1, 1).as_ref()])?;
+        let arr = arr.as_any().downcast_ref::<MapArray>().unwrap();
+        assert_eq!(
+            collect_map_array(arr),
+            expected[1..3]
+                .iter()
+                .chain(&expected[4..])
+                .cloned()
+                .collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_maps_merge_keys() -> Result<()> {
+        let map_1 = create_map_array(&[
+            Some(vec![("a", Some(1)), ("b", Some(2))]),
+            Some(vec![("c", Some(3))]),
+            None,
+            None,
+            Some(vec![("a", Some(1)), ("a", Some(2))]),
+        ]);
+        let map_2 = create_map_array(&[
+            Some(vec![("b", None), ("d", Some(4))]),
+            Some(vec![]),
+            Some(vec![("e", Some(5))]),
+            None,
+            Some(vec![("b", Some(3))]),
+        ]);
+        let map_3 = create_map_array(&[
+            Some(vec![("a", Some(6))]),
+            None,
+            Some(vec![("e", Some(7)), ("f", Some(8))]),
+            None,
+            Some(vec![("a", Some(4))]),
+        ]);
+
+        let merged = concat_maps_merge_keys(&[&map_1, &map_2, &map_3])?;
+        let entries = |entries: Vec<(&str, Option<i32>)>| {
+            Some(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(
+            collect_map_array(&merged),
+            vec![
+                entries(vec![("a", Some(6)), ("b", None), ("d", Some(4))]),
+                entries(vec![("c", Some(3))]),
+                entries(vec![("e", Some(7)), ("f", Some(8))]),
+                None,
+                entries(vec![("a", Some(4)), ("b", Some(3))]),
+            ]
+        );
+
+        // sliced inputs
+        let map_1 = map_1.slice(1, 2);
+        let map_2 = map_2.slice(2, 2);
+        let merged = concat_maps_merge_keys(&[
+            map_1.as_any().downcast_ref().unwrap(),
+            map_2.as_any().downcast_ref().unwrap(),
+        ])?;
+        assert_eq!(
+            collect_map_array(&merged),
+            vec![entries(vec![("c", Some(3)), ("e", Some(5))]), None]
+        );
+
+        let short = create_map_array(&[None]);
+        let error = concat_maps_merge_keys(&[&merged, &short]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: concat_maps_merge_keys requires arrays of the same length, arrays[0] has length 2 but arrays[1] has length 1"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_map_arrays_different_fields() {
+        let map_1 = create_map_array(&[Some(vec![("a", Some(1))])]);
+        let entries_field = match map_1.data_type() {
+            DataType::Map(field, _) => field.clone(),
+            _ => unreachable!(),
+        };
+        let sorted = ArrayData::builder(DataType::Map(entries_field, true))
+            .len(map_1.len())
+            .buffers(map_1.data().buffers().to_vec())
+            .child_data(map_1.data().child_data().to_vec())
+            .build()
+            .unwrap();
+        let map_2 = MapArray::from(sorted);
+
+        let error = concat(&[&map_1, &map_2]).unwrap_err().to_string();
+        assert!(
+            error.starts_with("Invalid argument error: It is not possible to concatenate map arrays with different entries"),
+            "{}",
+            error
+        );
+        assert!(error.contains("(keys sorted: false) and"), "{}", error);
+        assert!(error.ends_with("(keys sorted: true)"), "{}", error);
+    }
+
+    #[derive(Clone, Copy)]
+    enum UnionValue {
+        Int(i32),
+        Float(f64),
+        NullInt,
+        NullFloat,
+    }
+
+    fn create_union_array(values: &[UnionValue], dense: bool) -> UnionArray {
+        let mut builder = if dense {
+            UnionBuilder::new_dense(values.len())
+        } else {
+            UnionBuilder::new_sparse(values.len())
+        };
+        for value in values {
+            match value {
+                UnionValue::Int(v) => builder.append::<Int32Type>("a", *v).unwrap(),
+                UnionValue::Float(v) => builder.append::<Float64Type>("b", *v).unwrap(),
+                UnionValue::NullInt => builder.append_null::<Int32Type>("a").unwrap(),
+                UnionValue::NullFloat => builder.append_null::<Float64Type>("b").unwrap(),
+            }
+        }
+        builder.build().unwrap()
+    }
+
+    fn test_concat_union_arrays(dense: bool) -> Result<()> {
+        use UnionValue::*;
+        let values_1 = [Int(1), NullFloat, Float(3.0), Int(4)];
+        let values_2 = [Int(5), NullInt, Float(7.5)];
+        let values_3 = [Int(8), Float(9.0), NullFloat, Int(10)];
+        let union_1 = create_union_array(&values_1, dense);
+        let union_2 = create_union_array(&values_2, dense);
+        let union_3 = create_union_array(&values_3, dense);
+
+        let arr = concat(&[&union_1, &union_2, union_3.slice(1, 2).as_ref()])?;
+
+        let expected = values_1
+            .iter()
+            .chain(&values_2)
+            .chain(&values_3[1..3])
+            .cloned()
+            .collect::<Vec<_>>();
+        let expected = create_union_array(&expected, dense);
+        assert_eq!(arr.data_type(), expected.data_type());
+        assert_eq!(arr.len(), 9);
+
+        let arr = arr.as_any().downcast_ref::<UnionArray>().unwrap();
+        for i in 0..expected.len() {
+            assert_eq!(arr.type_id(i), expected.type_id(i));
+            assert_eq!(&arr.value(i), &expected.value(i), "index {}", i);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_sparse_union_arrays() -> Result<()> {
+        test_concat_union_arrays(false)
+    }
+
+    #[test]
+    fn test_concat_dense_union_arrays() -> Result<()> {
+        test_concat_union_arrays(true)
+    }
+
+    #[test]
+    fn test_concat_dense_union_compacts_children() {
+        let dense_union = |type_ids: Vec<i8>, offsets: Vec<i32>, ints, floats| {
+            UnionArray::try_new(
+                &[0, 1],
+                Buffer::from_slice_ref(&type_ids),
+                Some(Buffer::from_slice_ref(&offsets)),
+                vec![
+                    (
+                        Field::new("a", DataType::Int32, false),
+                        Arc::new(Int32Array::from(ints)) as ArrayRef,
+                    ),
+                    (
+                        Field::new("b", DataType::Float64, false),
+                        Arc::new(Float64Array::from(floats)) as ArrayRef,
+                    ),
+                ],
+            )
+            .unwrap()
+        };
+        // only uses the first and last slots of its int child
+        let ints_only =
+            dense_union(vec![0, 0], vec![0, 2], vec![1, 2, 3], vec![9.0, 9.5]);
+        // only uses the second slot of its float child
+        let floats_only = dense_union(vec![1], vec![1], vec![7], vec![0.5, 1.5]);
+
+        let result = concat(&[&ints_only, &floats_only]).unwrap();
+        let result = result.as_any().downcast_ref::<UnionArray>().unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result.child(0).as_ref(),
+            &Int32Array::from(vec![1, 3]) as &dyn Array
+        );
+        assert_eq!(
+            result.child(1).as_ref(),
+            &Float64Array::from(vec![1.5]) as &dyn Array
+        );
+        assert_eq!(
+            (0..3).map(|i| result.value_offset(i)).collect::<Vec<_>>(),
+            vec![0, 1, 0]
+        );
+    }
+
+    #[test]
+    fn test_concat_dense_union_unsupported_type_ids() {
+        let union = |value_offsets: Option<Buffer>| {
+            UnionArray::try_new(
+                &[5, 7],
+                Buffer::from_slice_ref(&[5i8, 7]),
+                value_offsets,
+                vec![
+                    (
+                        Field::new("a", DataType::Int32, false),
+                        Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+                    ),
+                    (
+                        Field::new("b", DataType::Float64, false),
+                        Arc::new(Float64Array::from(vec![1.0, 2.0])) as ArrayRef,
+                    ),
+                ],
+            )
+            .unwrap()
+        };
+        let dense = union(Some(Buffer::from_slice_ref(&[0i32, 0])));
+
+        let error = concat(&[&dense, dense.slice(1, 1).as_ref()]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Not yet implemented: concat of dense union arrays with type ids [5, 7] that are not the indices of their fields"
+        );
+        assert!(concat_n(&dense, 2).is_err());
+
+        // nested in another type
+        let list = ListArray::from(
+            ArrayData::builder(DataType::List(Box::new(Field::new(
+                "item",
+                dense.data_type().clone(),
+                false,
+            ))))
+            .len(1)
+            .add_buffer(Buffer::from_slice_ref(&[0i32, 2]))
+            .add_child_data(dense.data().clone())
+            .build()
+            .unwrap(),
+        );
+        assert!(concat(&[&list, &list.slice(0, 1)]).is_err());
+        let mut builder = ConcatBuilder::new(list.data_type().clone());
+        assert!(builder.push(&list).is_err());
+
+        // sparse unions do not index their children by type id
+        let sparse = union(None);
+        let result = concat(&[&sparse, sparse.slice(1, 1).as_ref()]).unwrap();
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn test_concat_union_arrays_different_modes() {
+        let sparse = create_union_array(&[UnionValue::Int(1)], false);
+        let dense = create_union_array(&[UnionValue::Int(1)], true);
+
+        let error = concat(&[&sparse, &dense]).unwrap_err().to_string();
+        assert!(
+            error.starts_with("Invalid argument error: It is not possible to concatenate union arrays with different fields, type ids or modes"),
+            "{}",
+            error
+        );
+        assert!(error.ends_with("[0] Sparse and [Field { name: \"a\", data_type: Int32, nullable: false, dict_id: 0, dict_is_ordered: false, metadata: None }] [0] Dense"), "{}", error);
+    }
+
+    #[test]
+    fn test_concat_struct_arrays_metadata() -> Result<()> {
+        let metadata: BTreeMap<String, String> =
+            [("k".to_string(), "v".to_string())].into_iter().collect();
+        let field =
+            Field::new("field", DataType::Int64, true).with_metadata(Some(metadata));
+        let input_1 = StructArray::from(vec![(
+            field.clone(),
+            Arc::new(Int64Array::from(vec![Some(1), None])) as ArrayRef,
+        )]);
+        let input_2 = StructArray::from(vec![(
+            field.clone(),
+            Arc::new(Int64Array::from(vec![Some(3)])) as ArrayRef,
+        )]);
+
+        let arr = concat(&[&input_1, &input_2])?;
+        assert_eq!(arr.data_type(), &DataType::Struct(vec![field.clone()]));
+        let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(
+            arr.column(0).as_ref(),
+            &Int64Array::from(vec![Some(1), None, Some(3)]) as &dyn Array
+        );
+
+        // fields only differing by their metadata cannot be concatenated
+        let other_field = field.with_metadata(None);
+        let input_3 = StructArray::from(vec![(
+            other_field,
+            Arc::new(Int64Array::from(vec![Some(4)])) as ArrayRef,
+        )]);
+        let error = concat(&[&input_1, &input_3]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: It is not possible to concatenate struct arrays with different fields: \
+            Field { name: \"field\", data_type: Int64, nullable: true, dict_id: 0, dict_is_ordered: false, metadata: Some({\"k\": \"v\"}) } and \
+            Field { name: \"field\", data_type: Int64, nullable: true, dict_id: 0, dict_is_ordered: false, metadata: None }"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_list_sizes() -> Result<()> {
+        let list = |len: usize| {
+            ListArray::from_iter_primitive::<Int64Type, _, _>(
+                (0..len).map(|i| Some(vec![Some(i as i64); 10])),
+            )
+        };
+        let a = list(4);
+        let b = list(5);
+        let c = list(4);
+        // (4 + 5 + 3) * 10 * 8 = 960 bytes of child values
+
+        let arr = concat(&[&a, &b, c.slice(1, 3).as_ref()])?;
+        assert_eq!(arr.len(), 12);
+        // without the child capacity this would have grown to 1024
+        assert_eq!(arr.data().child_data()[0].buffers()[0].capacity(), 960);
+
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(
+            (0..4)
+                .chain(0..5)
+                .chain(1..4)
+                .map(|i| Some(vec![Some(i as i64); 10])),
+        );
+        assert_eq!(arr.as_ref(), &expected as &dyn Array);
+
+        let a = LargeListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1); 30]),
+            None,
+            Some(vec![]),
+        ]);
+        let b = LargeListArray::from_iter_primitive::<Int64Type, _, _>(vec![Some(vec![
+                None;
+                50
+            ])]);
+        // (30 + 50) * 8 = 640 bytes of child values
+        let arr = concat(&[&a, &b])?;
+        assert_eq!(arr.data().child_data()[0].buffers()[0].capacity(), 640);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_primitive_typed() -> Result<()> {
+        let a = Int32Array::from(vec![Some(1), None]);
+        let b = Int32Array::from(vec![3, 4, 5]);
+        let c = Int32Array::from(vec![Some(6), None, Some(8)]);
+
+        let arr = concat_primitive(&[&a, &b, &c])?;
+        assert_eq!(arr.len(), 8);
+        assert_eq!(arr.null_count(), 2);
+        assert_eq!(arr.values(), &[1, 0, 3, 4, 5, 6, 0, 8]);
+        assert_eq!(
+            arr,
+            Int32Array::from(vec![
+                Some(1),
+                None,
+                Some(3),
+                Some(4),
+                Some(5),
+                Some(6),
+                None,
+                Some(8)
+            ])
+        );
+
+        let arr = concat_primitive(&[&a])?;
+        assert_eq!(arr, a);
+
+        assert!(concat_primitive::<Int32Type>(&[]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_primitive_typed_different_timezones() {
+        let a = TimestampSecondArray::from_vec(vec![1], Some("UTC".to_string()));
+        let b = TimestampSecondArray::from_vec(vec![2], Some("+01:00".to_string()));
+
+        assert!(concat_primitive(&[&a, &b]).is_err());
+    }
+
+    #[test]
+    fn test_concat_skips_empty_arrays() -> Result<()> {
+        let empty = Int64Array::from(Vec::<i64>::new());
+        let a = Int64Array::from(vec![Some(1), None]);
+        let b = Int64Array::from(vec![3, 4, 5]);
+        let c = Int64Array::from(vec![6, 7, 8]);
+
+        let arr = concat(&[&empty, &a, &empty, &b, &empty, c.slice(3, 0).as_ref()])?;
+        assert_eq!(&arr, &concat(&[&a, &b])?);
+
+        // empty arrays still need to have the same data type
+        let empty_string = StringArray::from(Vec::<&str>::new());
+        assert!(concat(&[&a, &empty_string, &b]).is_err());
+
+        let arr = concat(&[&empty, c.slice(1, 0).as_ref()])?;
+        assert_eq!(arr.data_type(), &DataType::Int64);
+        assert!(arr.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_iter() -> Result<()> {
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(vec![Some("a"), None])),
+            Arc::new(StringArray::from(vec!["b", "c"])),
+            Arc::new(StringArray::from(vec!["d"])),
+        ];
+
+        let arr = concat_iter(arrays.iter().map(|a| a.as_ref()))?;
+        let expected = concat(&arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>())?;
+        assert_eq!(&arr, &expected);
+
+        let arr = concat_iter(arrays.iter().take(1).map(|a| a.as_ref()))?;
+        assert_eq!(&arr, &arrays[0]);
+
+        assert!(concat_iter(std::iter::empty()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_single_non_empty_array() -> Result<()> {
+        let empty = StringArray::from(Vec::<&str>::new());
+        let array = StringArray::from(vec![Some("a"), None, Some("b")]);
+        let sliced = array.slice(1, 2);
+
+        let arr = concat(&[&empty, &array, &empty])?;
+        assert!(arr.data().ptr_eq(array.data()));
+
+        let arr = concat(&[&empty, sliced.as_ref(), array.slice(3, 0).as_ref()])?;
+        assert!(arr.data().ptr_eq(sliced.data()));
+        assert_eq!(
+            arr.as_ref(),
+            &StringArray::from(vec![None, Some("b")]) as &dyn Array
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_filtered() {
+        let a = Int32Array::from_iter((0..100).map(|i| (i % 7 != 0).then(|| i)));
+        let b = Int32Array::from_iter_values(100..300).slice(50, 150);
+        let c = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let arrays: [&dyn Array; 3] = [&a, b.as_ref(), &c];
+
+        let densities: [fn(usize) -> Option<bool>; 5] = [
+            |_| Some(false),
+            |_| Some(true),
+            |i| Some(i % 2 == 0),
+            |i| Some(i % 64 < 40),
+            |i| (i % 3 != 0).then(|| i % 5 != 0),
+        ];
+        for density in densities {
+            let masks = arrays
+                .iter()
+                .map(|array| (0..array.len()).map(density).collect::<BooleanArray>())
+                .collect::<Vec<_>>();
+            let masks = masks.iter().collect::<Vec<_>>();
+
+            let filtered = arrays
+                .iter()
+                .zip(&masks)
+                .map(|(array, mask)| crate::compute::filter(*array, mask).unwrap())
+                .collect::<Vec<_>>();
+            let expected =
+                concat(&filtered.iter().map(|a| a.as_ref()).collect::<Vec<_>>()).unwrap();
+
+            let result = concat_filtered(&arrays, &masks).unwrap();
+            assert_eq!(result.as_ref(), expected.as_ref());
+        }
+    }
+
+    #[test]
+    fn test_concat_filtered_invalid() {
+        let a = Int32Array::from(vec![1, 2]);
+        let b = StringArray::from(vec!["a", "b"]);
+        let mask = BooleanArray::from(vec![true, false]);
+        let short_mask = BooleanArray::from(vec![true]);
+
+        let error = concat_filtered(&[&a, &a], &[&mask]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: concat_filtered requires one mask per array, got 2 arrays and 1 masks"
+        );
+
+        let error = concat_filtered(&[&a, &a], &[&mask, &short_mask]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: masks[1] has length 1 but arrays[1] has length 2"
+        );
+
+        let error = concat_filtered(&[&a, &b], &[&mask, &mask]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: It is not possible to concatenate arrays of different data types: array 0 has type Int32 but array 1 has type Utf8"
+        );
+    }
+
+    #[test]
+    fn test_concat_to_dictionary() {
+        let a: StringArray = (0..100).map(|i| Some(["foo", "bar"][i % 2])).collect();
+        let b: StringArray = (0..100)
+            .map(|i| (i % 10 != 0).then(|| ["bar", "baz"][i % 2]))
+            .collect();
+
+        let result = concat_to_dictionary::<Int32Type>(&[&a, &b]).unwrap();
+        let array = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int32Type>>()
+            .unwrap();
+        assert_eq!(array.len(), 200);
+        assert_eq!(array.keys().null_count(), 10);
+
+        let values = array.values();
+        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(values, &StringArray::from(vec!["foo", "bar", "baz"]));
+
+        let expected = concat(&[&a, &b]).unwrap();
+        let decoded = cast(&result, &DataType::Utf8).unwrap();
+        assert_eq!(decoded.as_ref(), expected.as_ref());
+    }
+
+    #[test]
+    fn test_concat_to_dictionary_overflow() {
+        let a = Int32Array::from_iter_values(0..100);
+        let b = Int32Array::from_iter_values(100..200);
+
+        let array = concat_to_dictionary::<Int8Type>(&[&a, &a]).unwrap();
+        assert_eq!(array.data().child_data()[0].len(), 100);
+
+        let error = concat_to_dictionary::<Int8Type>(&[&a, &b]).unwrap_err();
+        assert!(matches!(error, ArrowError::DictionaryKeyOverflowError));
+    }
+
+    #[test]
+    fn test_concat_as() {
+        let a = Int32Array::from(vec![Some(1), None, Some(i32::MAX)]);
+        let b = Int64Array::from(vec![i64::MAX, 4]);
+        let c = StringArray::from(vec!["5"]);
+
+        let array = concat_as(&[&a, &b, &c], &DataType::Int64).unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &Int64Array::from(vec![
+                Some(1),
+                None,
+                Some(i32::MAX as i64),
+                Some(i64::MAX),
+                Some(4),
+                Some(5)
+            ]) as &dyn Array
+        );
+
+        let error = concat_as(&[], &DataType::Int64).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Compute error: concat requires input of at least one array"
+        );
+    }
+
+    #[test]
+    fn test_concat_as_invalid() {
+        let a = Int32Array::from(vec![1, 2]);
+        let b = StringArray::from(vec!["3", "four"]);
+        assert!(matches!(
+            concat_as(&[&a, &b], &DataType::Int32),
+            Err(ArrowError::CastError(_))
+        ));
+
+        let c =
+            ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+        assert!(concat_as(&[&a, &c], &DataType::Int32).is_err());
+    }
+
+    #[test]
+    fn test_concat_compact_utf8() {
+        let a = LargeStringArray::from(vec![Some("foo"), None]);
+        let b = LargeStringArray::from(vec!["bar", "baz"]).slice(1, 1);
+
+        let array = concat_compact_utf8(&[&a, b.as_ref()]).unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &StringArray::from(vec![Some("foo"), None, Some("baz")]) as &dyn Array
+        );
+
+        let c = StringArray::from(vec!["foo"]);
+        let error = concat_compact_utf8(&[&a, &c]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: concat_compact_utf8 requires LargeUtf8 arrays, got Utf8"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "force_validate"))]
+    fn test_concat_compact_utf8_large() {
+        // zeroed value buffers are lazily allocated, so this is cheap
+        let len = i32::MAX as usize + 1;
+        let values = Buffer::from(MutableBuffer::from_len_zeroed(len));
+        let offsets = Buffer::from_slice_ref(&[0, len as i64]);
+        let data = unsafe {
+            ArrayData::builder(DataType::LargeUtf8)
+                .len(1)
+                .add_buffer(offsets)
+                .add_buffer(values)
+                .build_unchecked()
+        };
+        let large = LargeStringArray::from(data);
+
+        let array = concat_compact_utf8(&[&large]).unwrap();
+        assert_eq!(array.data_type(), &DataType::LargeUtf8);
+        assert_eq!(array.len(), 1);
+    }
+
+    #[test]
+    fn test_concat_with_offsets() {
+        let a = Int32Array::from(vec![1, 2, 3]);
+        let b = Int32Array::from(Vec::<i32>::new());
+        let c = Int32Array::from(vec![Some(4), None]);
+        let arrays: [&dyn Array; 3] = [&a, &b, &c];
+
+        let (array, offsets) = concat_with_offsets(&arrays).unwrap();
+        assert_eq!(array.as_ref(), concat(&arrays).unwrap().as_ref());
+        assert_eq!(offsets, vec![0, 3, 3]);
+        for (input, offset) in arrays.iter().zip(&offsets) {
+            assert_eq!(array.slice(*offset, input.len()).as_ref(), *input);
+        }
+
+        let (array, offsets) = concat_with_offsets(&[&a]).unwrap();
+        assert_eq!(array.len(), 3);
+        assert_eq!(offsets, vec![0]);
+
+        assert!(concat_with_offsets(&[]).is_err());
+    }
+
+    #[test]
+    fn test_concat_adjacent_slices() {
+        let array = Int64Array::from_iter_values(0..100);
+        let values = array.data().buffers()[0].as_ptr();
+        let a = array.slice(10, 20);
+        let b = array.slice(30, 0);
+        let c = array.slice(30, 50);
+        let d = array.slice(80, 5);
+
+        let result = concat(&[a.as_ref(), b.as_ref(), c.as_ref(), d.as_ref()]).unwrap();
+        assert_eq!(result.as_ref(), array.slice(10, 75).as_ref());
+        assert_eq!(result.data().buffers()[0].as_ptr(), values);
+
+        // gaps and nulls require a copy
+        let result = concat(&[a.as_ref(), d.as_ref()]).unwrap();
+        assert_ne!(result.data().buffers()[0].as_ptr(), values);
+        let expected = Int64Array::from_iter_values((10..30).chain(80..85));
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let array = Int64Array::from(vec![Some(1), None, Some(3)]);
+        let result =
+            concat(&[array.slice(0, 1).as_ref(), array.slice(1, 2).as_ref()]).unwrap();
+        assert_ne!(
+            result.data().buffers()[0].as_ptr(),
+            array.values().as_ptr() as _
+        );
+        assert_eq!(result.as_ref(), &array as &dyn Array);
+    }
+
+    #[test]
+    fn test_concat_fixed_width() {
+        let a = Int32Array::from(vec![Some(1), None]);
+        let b = Int32Array::from(vec![3, 4, 5]).slice(1, 2);
+        let c = create_decimal_array(&[Some(1), Some(2)], 5, 2);
+        let d = create_decimal_array(&[Some(3), None], 5, 2);
+
+        let inputs = [
+            (0..10_000)
+                .map(|i| if i % 3 == 0 { a.data() } else { b.data() })
+                .collect::<Vec<_>>(),
+            (0..10_000)
+                .map(|i| if i % 2 == 0 { c.data() } else { d.data() })
+                .collect::<Vec<_>>(),
+        ];
+        for arrays in inputs {
+            let mut mutable = MutableArrayData::new(arrays.clone(), false, 0);
+            for (i, array) in arrays.iter().enumerate() {
+                mutable.extend(i, 0, array.len())
+            }
+            let expected = make_array(mutable.freeze());
+
+            let result = concat_data(&arrays).unwrap();
+            result.validate_full().unwrap();
+            let result = make_array(result);
+            assert_eq!(result.len(), 20_000);
+            assert_eq!(result.as_ref(), expected.as_ref());
+            assert_eq!(result.null_count(), expected.null_count());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_concat_fixed_width_parallel() {
+        let a = Int64Array::from_iter((0..1000).map(|i| (i % 3 != 0).then(|| i)));
+        let b = Int64Array::from_iter_values(1000..2000).slice(17, 500);
+        let c = Int64Array::from(vec![Some(1), None, Some(3)]).slice(1, 2);
+        let d = FixedSizeBinaryArray::try_from_sparse_iter(
+            (0..100).map(|i| (i % 5 != 0).then(|| [i as u8; 3])),
+        )
+        .unwrap();
+        let e = FixedSizeBinaryArray::try_from_iter((0..50).map(|i| [i as u8; 3]))
+            .unwrap()
+            .slice(3, 40);
+
+        let inputs: [Vec<&ArrayData>; 2] =
+            [vec![a.data(), b.data(), c.data()], vec![d.data(), e.data()]];
+        for arrays in inputs {
+            let mut mutable = MutableArrayData::new(arrays.clone(), false, 0);
+            for (i, array) in arrays.iter().enumerate() {
+                mutable.extend(i, 0, array.len())
+            }
+            let serial = make_array(mutable.freeze());
+
+            let byte_width = fixed_byte_width(arrays[0].data_type()).unwrap();
+            let parallel = make_array(concat_fixed_width_parallel(&arrays, byte_width));
+            parallel.data().validate_full().unwrap();
+
+            assert_eq!(parallel.as_ref(), serial.as_ref());
+            assert_eq!(parallel.null_count(), serial.null_count());
+        }
+
+        assert_eq!(fixed_byte_width(&DataType::Utf8), None);
+        assert_eq!(fixed_byte_width(&DataType::Boolean), None);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_concat_parallel_threshold() {
+        // 4 inputs of 40000 i64 values are above both thresholds
+        let arrays = (0..4)
+            .map(|i| Int64Array::from_iter((0..40_000).map(|j| (j % 7 != i).then(|| j))))
+            .collect::<Vec<_>>();
+        let arrays = arrays.iter().map(|a| a as &dyn Array).collect::<Vec<_>>();
+        let len = arrays.iter().map(|a| a.len()).sum::<usize>();
+        assert!(arrays.len() >= PARALLEL_MIN_ARRAYS && len * 8 >= PARALLEL_MIN_BYTES);
+
+        let result = concat(&arrays).unwrap();
+        result.data().validate_full().unwrap();
+        let expected = arrays
+            .iter()
+            .flat_map(|a| a.as_any().downcast_ref::<Int64Array>().unwrap().iter())
+            .collect::<Int64Array>();
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_concat_dictionary_different_key_types() {
+        let a: DictionaryArray<Int8Type> = vec!["a", "b"].into_iter().collect();
+        let b: DictionaryArray<Int32Type> = vec!["c"].into_iter().collect();
+
+        let error = concat(&[&a, &b]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: It is not possible to concatenate dictionary arrays with different key types: Int8 and Int32, consider concat_dictionaries_promote_keys"
+        );
+    }
+
+    #[test]
+    fn test_concat_dictionaries_promote_keys() {
+        let a: DictionaryArray<Int8Type> =
+            vec![Some("a"), None, Some("b")].into_iter().collect();
+        let b: DictionaryArray<UInt8Type> = vec!["c", "a"].into_iter().collect();
+        let c: DictionaryArray<Int16Type> = vec!["d"].into_iter().collect();
+
+        let array = concat_dictionaries_promote_keys(&[&a, &b, &c]).unwrap();
+        let array = array
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int16Type>>()
+            .unwrap();
+        let keys = array.keys().iter().collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            vec![Some(0), None, Some(1), Some(2), Some(3), Some(4)]
+        );
+        let values = array.values();
+        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(values, &StringArray::from(vec!["a", "b", "c", "a", "d"]));
+
+        // the widest key type of the inputs cannot index the merged values
+        let strings = (0..200).map(|i| i.to_string()).collect::<Vec<_>>();
+        let a: DictionaryArray<Int8Type> =
+            strings[..100].iter().map(|s| s.as_str()).collect();
+        let b: DictionaryArray<Int8Type> =
+            strings[100..].iter().map(|s| s.as_str()).collect();
+        let error = concat_dictionaries_promote_keys(&[&a, &b]).unwrap_err();
+        assert!(matches!(error, ArrowError::DictionaryKeyOverflowError));
+
+        let error = concat_dictionaries_promote_keys(&[&a, &Int32Array::from(vec![1])])
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: concat_dictionaries_promote_keys requires dictionary arrays with the same value type, got Dictionary(Int8, Utf8) and Int32"
+        );
+    }
+
+    #[test]
+    fn test_concat_dictionaries_promote_keys_shared_values() {
+        let values = StringArray::from(vec!["a", "b", "c"]);
+        let a = DictionaryArray::<Int8Type>::try_new(
+            &Int8Array::from(vec![Some(0), None, Some(2)]),
+            &values,
+        )
+        .unwrap();
+        let b = DictionaryArray::<Int16Type>::try_new(
+            &Int16Array::from(vec![0, 1, 2, 1]),
+            &values,
+        )
+        .unwrap();
+        let b = b.slice(1, 3);
+        assert!(a.values().data().ptr_eq(&b.data().child_data()[0]));
+
+        let array = concat_dictionaries_promote_keys(&[&a, b.as_ref()]).unwrap();
+        let array = array
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int16Type>>()
+            .unwrap();
+        // the shared values are reused rather than concatenated
+        assert!(array.values().data().ptr_eq(values.data()));
+        let keys = array.keys().iter().collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            vec![Some(0), None, Some(2), Some(1), Some(2), Some(1)]
+        );
+
+        let expected = concat_dictionaries_promote_keys(&[
+            &a,
+            b.as_ref(),
+            // an empty dictionary with other values disables the fast path
+            &DictionaryArray::<Int8Type>::from_iter(Vec::<&str>::new()),
+        ])
+        .unwrap();
+        let expected = expected
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int16Type>>()
+            .unwrap();
+        let expected_values = expected.values();
+        let expected_values = expected_values
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let strings = |array: &DictionaryArray<Int16Type>, values: &StringArray| {
+            array
+                .keys()
+                .iter()
+                .map(|k| k.map(|k| values.value(k as usize).to_string()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(strings(array, &values), strings(expected, expected_values));
+    }
+
+    #[test]
+    fn test_concat_into() {
+        let a = Int64Array::from_iter((0..100).map(|i| (i % 3 != 0).then(|| i)));
+        let b = Int64Array::from_iter_values(100..200).slice(10, 50);
+        let b = b.as_any().downcast_ref::<Int64Array>().unwrap();
+        let c = Int64Array::from(vec![Some(1), None, Some(3)]);
+        let arrays = [&a, b, &c];
+
+        let mut values = MutableBuffer::new(153 * 8);
+        let ptr = values.as_ptr();
+        let nulls = concat_into(&arrays, &mut values).unwrap();
+        // the preallocated buffer was large enough
+        assert_eq!(values.as_ptr(), ptr);
+
+        let data = ArrayData::builder(DataType::Int64)
+            .len(153)
+            .add_buffer(values.into())
+            .null_bit_buffer(nulls)
+            .build()
+            .unwrap();
+        let expected = concat_primitive(&arrays).unwrap();
+        assert_eq!(Int64Array::from(data), expected);
+
+        let mut values = MutableBuffer::new(0);
+        let nulls = concat_into(&[b], &mut values).unwrap();
+        assert_eq!(nulls, None);
+        assert_eq!(values.as_slice(), b.values().to_byte_slice());
+    }
+
+    #[test]
+    fn test_concat_struct_of_list_sizes() -> Result<()> {
+        let struct_array = |len: usize| {
+            let list = ListArray::from_iter_primitive::<Int64Type, _, _>(
+                (0..len).map(|i| Some(vec![Some(i as i64); 10])),
+            );
+            let name = (0..len).map(|_| Some("foo")).collect::<StringArray>();
+            StructArray::from(vec![
+                (
+                    Field::new("list", list.data_type().clone(), true),
+                    Arc::new(list) as ArrayRef,
+                ),
+                (
+                    Field::new("name", DataType::Utf8, true),
+                    Arc::new(name) as ArrayRef,
+                ),
+            ])
+        };
+        let a = struct_array(4);
+        let b = struct_array(5);
+        let c = struct_array(20);
+        // (4 + 5 + 3) * 10 * 8 = 960 bytes of list values
+        // (4 + 5 + 3) * 3 = 36 bytes of names
+
+        let arr = concat(&[&a, &b, c.slice(1, 3).as_ref()])?;
+        assert_eq!(arr.len(), 12);
+        let list = &arr.data().child_data()[0];
+        let name = &arr.data().child_data()[1];
+        assert_eq!(list.child_data()[0].buffers()[0].capacity(), 960);
+        assert_eq!(name.buffers()[1].capacity(), 64);
+
+        let expected = concat(&[&struct_array(4), &struct_array(5)])?;
+        assert_eq!(arr.slice(0, 9).as_ref(), expected.as_ref());
+        let c = c.slice(1, 3);
+        assert_eq!(arr.slice(9, 3).as_ref(), c.as_ref());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_dedup() {
+        let a = Int32Array::from(vec![1, 2, 3]);
+        let b = Int32Array::from(vec![3, 4, 5]);
+        let array = concat_dedup(&[&a, &b]).unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &Int32Array::from(vec![1, 2, 3, 4, 5]) as &dyn Array
+        );
+
+        // duplicates within an input are kept, empty inputs are ignored
+        let a = StringArray::from(vec![Some("a"), Some("a"), None]);
+        let b = StringArray::from(Vec::<&str>::new());
+        let c = StringArray::from(vec![None, None, Some("b")]);
+        let d = StringArray::from(vec!["b"]);
+        let e = StringArray::from(vec!["c"]);
+        let array = concat_dedup(&[&a, &b, &c, &d, &e]).unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &StringArray::from(vec![
+                Some("a"),
+                Some("a"),
+                None,
+                None,
+                Some("b"),
+                Some("c")
+            ]) as &dyn Array
+        );
+
+        let array = concat_dedup(&[&b, &b]).unwrap();
+        assert!(array.is_empty());
+    }
+
+    #[test]
+    fn test_concat_dedup_null_arrays() {
+        let nulls = NullArray::new(2);
+        let a = Int32Array::from(vec![None, Some(1)]);
+
+        let array = concat_dedup(&[&nulls, &a]).unwrap();
+        let expected = Int32Array::from(vec![None, None, Some(1)]);
+        assert_eq!(array.as_ref(), &expected as &dyn Array);
+
+        let empty = NullArray::new(0);
+        let array =
+            concat_dedup(&[&empty, &Int32Array::from(Vec::<i32>::new())]).unwrap();
+        assert_eq!(array.data_type(), &DataType::Int32);
+    }
+
+    #[test]
+    fn test_concat_with_source_ids() {
+        let a = StringArray::from(vec!["a", "b"]);
+        let b = StringArray::from(Vec::<&str>::new());
+        let c = StringArray::from(vec![Some("c"), None, Some("d")]);
+
+        let (array, source_ids) = concat_with_source_ids(&[&a, &b, &c]).unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &StringArray::from(vec![Some("a"), Some("b"), Some("c"), None, Some("d")])
+                as &dyn Array
+        );
+        assert_eq!(source_ids, Int32Array::from(vec![0, 0, 2, 2, 2]));
+    }
+
+    #[test]
+    fn test_concat_structs_reorder() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "z"]));
+        let field_a = Field::new("a", DataType::Int32, true);
+        let field_b = Field::new("b", DataType::Utf8, false);
+
+        let ab = StructArray::from(vec![
+            (field_a.clone(), a.clone()),
+            (field_b.clone(), b.clone()),
+        ]);
+        let ba = StructArray::from(vec![(field_b.clone(), b), (field_a.clone(), a)]);
+        let ba = ba.slice(1, 2);
+
+        let array = concat_structs_reorder(&[&ab, ba.as_ref()]).unwrap();
+        let expected = StructArray::from(vec![
+            (
+                field_a,
+                Arc::new(Int32Array::from(vec![
+                    Some(1),
+                    None,
+                    Some(3),
+                    None,
+                    Some(3),
+                ])) as ArrayRef,
+            ),
+            (
+                field_b.clone(),
+                Arc::new(StringArray::from(vec!["x", "y", "z", "y", "z"])) as ArrayRef,
+            ),
+        ]);
+        assert_eq!(array.as_ref(), &expected as &dyn Array);
+
+        let c: ArrayRef = Arc::new(Int64Array::from(vec![1]));
+        let d: ArrayRef = Arc::new(StringArray::from(vec!["w"]));
+        let conflict = StructArray::from(vec![
+            (Field::new("a", DataType::Int64, true), c.clone()),
+            (field_b.clone(), d.clone()),
+        ]);
+        let error = concat_structs_reorder(&[&ab, &conflict]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: It is not possible to concatenate struct arrays with different fields: field a is Int32 and Int64"
+        );
+
+        let missing = StructArray::from(vec![
+            (
+                Field::new("c", DataType::Int32, true),
+                Arc::new(Int32Array::from(vec![1])) as ArrayRef,
+            ),
+            (field_b, d),
+        ]);
+        let error = concat_structs_reorder(&[&ab, &missing]).unwrap_err();
+        assert!(error.to_string().contains("field a is missing"));
+
+        let error = concat_structs_reorder(&[&ab, &c]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: concat_structs_reorder requires struct arrays, got Int64"
+        );
+    }
+
+    #[test]
+    fn test_concat_output_size() {
+        let list = |len: usize| {
+            ListArray::from_iter_primitive::<Int64Type, _, _>(
+                (0..len).map(|i| (i % 3 != 0).then(|| vec![Some(i as i64); 10])),
+            )
+        };
+        let strings = |len: usize| {
+            (0..len)
+                .map(|i| Some(i.to_string()))
+                .collect::<StringArray>()
+        };
+        let dictionary = |len: usize| {
+            (0..len)
+                .map(|i| ["a", "b", "c"][i % 3])
+                .collect::<DictionaryArray<Int16Type>>()
+        };
+
+        let inputs: Vec<[ArrayRef; 2]> = vec![
+            [
+                Arc::new(Int32Array::from_iter(
+                    (0..100).map(|i| (i % 2 == 0).then(|| i)),
+                )),
+                Arc::new(Int32Array::from_iter_values(0..50)),
+            ],
+            [
+                Arc::new(BooleanArray::from(vec![true; 100])),
+                Arc::new(BooleanArray::from(vec![false; 30])),
+            ],
+            [Arc::new(strings(100)), Arc::new(strings(300))],
+            [Arc::new(list(100)), list(100).slice(10, 50)],
+            [Arc::new(dictionary(100)), Arc::new(dictionary(20))],
+        ];
+        for arrays in inputs {
+            let arrays = arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>();
+            let estimate = concat_output_size(&arrays).unwrap();
+            let result = concat(&arrays).unwrap();
+
+            // buffers are padded to 64 bytes
+            let actual = result.data().get_buffer_memory_size();
+            assert!(estimate <= actual, "{} > {}", estimate, actual);
+            assert!(actual - estimate < 64 * 4, "{} << {}", estimate, actual);
+        }
+
+        let error = concat_output_size(&[&Int32Array::from(vec![1]), &strings(1)]);
+        assert!(error.is_err());
+    }
+
+    #[test]
+    fn test_concat_chunked() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3), Some(4), Some(5)]);
+        let b = Int32Array::from(Vec::<i32>::new());
+        let c = Int32Array::from(vec![6, 7, 8, 9]);
+        let arrays: [&dyn Array; 3] = [&a, &b, &c];
+        let expected = concat(&arrays).unwrap();
+
+        let chunks = concat_chunked(&arrays, 3).unwrap();
+        let lengths = chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>();
+        assert_eq!(lengths, vec![3, 3, 3]);
+        for (i, chunk) in chunks.iter().enumerate() {
+            assert_eq!(chunk.as_ref(), expected.slice(i * 3, 3).as_ref());
+        }
+
+        for chunk_size in [1, 2, 4, 9, 100] {
+            let chunks = concat_chunked(&arrays, chunk_size).unwrap();
+            let chunks = chunks.iter().map(|c| c.as_ref()).collect::<Vec<_>>();
+            assert!(chunks.iter().all(|chunk| chunk.len() <= chunk_size));
+            assert_eq!(concat(&chunks).unwrap().as_ref(), expected.as_ref());
+        }
+
+        assert!(concat_chunked(&[&b], 3).unwrap().is_empty());
+        assert!(concat_chunked(&arrays, 0).is_err());
+    }
+
+    #[test]
+    fn test_concat_chunked_null_arrays() {
+        let nulls = NullArray::new(2);
+        let a = Int32Array::from(vec![1, 2, 3]);
+
+        let chunks = concat_chunked(&[&nulls, &a], 3).unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks
+            .iter()
+            .all(|chunk| chunk.data_type() == &DataType::Int32));
+        let chunks = chunks.iter().map(|c| c.as_ref()).collect::<Vec<_>>();
+        let expected = Int32Array::from(vec![None, None, Some(1), Some(2), Some(3)]);
+        assert_eq!(concat(&chunks).unwrap().as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_concat_null_arrays() {
+        let a = Int64Array::from(vec![1, 2]);
+        let nulls = NullArray::new(3);
+        let b = Int64Array::from(vec![Some(6), None]);
+
+        let array = concat(&[&a, &nulls, &b]).unwrap();
+        let expected =
+            Int64Array::from(vec![Some(1), Some(2), None, None, None, Some(6), None]);
+        assert_eq!(array.as_ref(), &expected as &dyn Array);
+
+        let array = concat(&[&nulls, &b]).unwrap();
+        assert_eq!(array.data_type(), &DataType::Int64);
+        assert_eq!(array.null_count(), 4);
+
+        let array = concat(&[&nulls, &nulls]).unwrap();
+        assert_eq!(array.data_type(), &DataType::Null);
+        assert_eq!(array.len(), 6);
+
+        let c = StringArray::from(vec!["c"]);
+        let error = concat(&[&a, &nulls, &c]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: It is not possible to concatenate arrays of different data types: array 0 has type Int64 but array 2 has type Utf8"
+        );
+    }
+
+    #[test]
+    fn test_concat_timestamp_timezone() {
+        let utc = Some("UTC".to_string());
+        let a = TimestampMicrosecondArray::from_vec(vec![1, 2], utc.clone());
+        let b = TimestampMicrosecondArray::from_opt_vec(vec![Some(3), None], utc.clone());
+        let c = TimestampMicrosecondArray::from_vec((0..100).collect(), utc.clone());
+
+        for arrays in [[&a as &dyn Array, &b], [&a, &c]] {
+            let array = concat(&arrays).unwrap();
+            assert_eq!(
+                array.data_type(),
+                &DataType::Timestamp(TimeUnit::Microsecond, utc.clone())
+            );
+        }
+
+        let d = TimestampMicrosecondArray::from_vec(vec![4], Some("+01:00".to_string()));
+        let error = concat(&[&a, &d]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: It is not possible to concatenate temporal arrays with different units or timezones: Timestamp(Microsecond, Some(\"UTC\")) and Timestamp(Microsecond, Some(\"+01:00\"))"
+        );
+
+        let e = TimestampMillisecondArray::from_vec(vec![4], utc);
+        assert!(concat(&[&a, &e]).is_err());
+    }
+
+    #[test]
+    fn test_concat_array_refs() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let result = concat_array_refs(std::slice::from_ref(&a)).unwrap();
+        assert!(Arc::ptr_eq(&a, &result));
+
+        let b = a.slice(1, 2);
+        let result = concat_array_refs(std::slice::from_ref(&b)).unwrap();
+        assert!(Arc::ptr_eq(&b, &result));
+
+        let result = concat_array_refs(&[a.clone(), b]).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &Int32Array::from(vec![1, 2, 3, 2, 3]) as &dyn Array
+        );
+
+        assert!(concat_array_refs(&[]).is_err());
+    }
+
+    #[test]
+    fn test_concat_array_refs_vec() -> Result<()> {
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(vec![Some("a"), None])),
+            Arc::new(StringArray::from(vec!["b", "c"])),
+            Arc::new(StringArray::from(vec![Some("d")])),
+        ];
+        let result = concat_array_refs(&arrays)?;
+        let expected =
+            concat(&[arrays[0].as_ref(), arrays[1].as_ref(), arrays[2].as_ref()])?;
+        assert_eq!(result.as_ref(), expected.as_ref());
+        assert_eq!(result.len(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_list_item_fields() {
+        let list = |field: Field| {
+            let values = Int32Array::from(vec![1, 2, 3]);
+            let data = ArrayData::builder(DataType::List(Box::new(field)))
+                .len(2)
+                .add_buffer(Buffer::from_slice_ref(&[0, 1, 3]))
+                .add_child_data(values.data().clone())
+                .build()
+                .unwrap();
+            ListArray::from(data)
+        };
+        let metadata = BTreeMap::from([("key".to_string(), "value".to_string())]);
+        let item =
+            Field::new("item", DataType::Int32, true).with_metadata(Some(metadata));
+        let a = list(item.clone());
+        let b = list(item.clone());
+
+        // the item field is kept
+        let array = concat(&[&a, &b]).unwrap();
+        assert_eq!(array.data_type(), &DataType::List(Box::new(item.clone())));
+        assert_eq!(array.len(), 4);
+
+        let c = list(Field::new("element", DataType::Int32, true));
+        let error = concat(&[&a, &c]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Invalid argument error: It is not possible to concatenate list arrays with different item fields: {:?} and {:?}",
+                item,
+                Field::new("element", DataType::Int32, true)
+            )
+        );
+    }
+
+    #[test]
+    fn test_concat_unchecked() {
+        let a = StringArray::from(vec![Some("a"), None]);
+        let b = StringArray::from(vec!["b", "c", "d"]).slice(1, 2);
+        let c = StringArray::from(Vec::<&str>::new());
+        let arrays = [&a as &dyn Array, b.as_ref(), &c];
+
+        let result = unsafe { concat_unchecked(&arrays) }.unwrap();
+        assert_eq!(result.as_ref(), concat(&arrays).unwrap().as_ref());
+
+        let result = unsafe { concat_unchecked(&[&a]) }.unwrap();
+        assert_eq!(result.as_ref(), &a as &dyn Array);
+
+        assert!(unsafe { concat_unchecked(&[]) }.is_err());
+    }
+
+    #[test]
+    fn test_gather_concat() {
+        let a = StringArray::from(vec![Some("a"), None, Some("c"), Some("d")]);
+        let b = StringArray::from(vec!["e", "f", "g"]);
+        let arrays = [&a as &dyn Array, &b];
+
+        let patterns = vec![
+            (vec![Some(0), Some(1), Some(2)], vec![Some(0), Some(1)]),
+            (vec![Some(3), Some(0), Some(3)], vec![Some(2), Some(2)]),
+            (vec![None, Some(1), None], vec![Some(1), None, Some(2)]),
+            (vec![], vec![Some(0)]),
+            (vec![None, None], vec![]),
+        ];
+        for (indices_a, indices_b) in patterns {
+            let indices_a = Int32Array::from(indices_a);
+            let indices_b = Int32Array::from(indices_b);
+
+            let result = gather_concat(&arrays, &[&indices_a, &indices_b]).unwrap();
+            let taken_a = take(&a, &indices_a, None).unwrap();
+            let taken_b = take(&b, &indices_b, None).unwrap();
+            let expected = concat(&[taken_a.as_ref(), taken_b.as_ref()]).unwrap();
+            assert_eq!(result.as_ref(), expected.as_ref());
+        }
+    }
+
+    #[test]
+    fn test_gather_concat_invalid() {
+        let a = Int32Array::from(vec![1, 2, 3]);
+        let b = Int32Array::from(vec![4, 5]);
+
+        let err = gather_concat(
+            &[&a, &b],
+            &[&Int32Array::from(vec![0]), &Int32Array::from(vec![2])],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Array index out of bounds, cannot get item at index 2 from 2 entries"
+        );
+
+        let err = gather_concat(&[&a, &b], &[&Int32Array::from(vec![0])]).unwrap_err();
+        assert!(err.to_string().contains("one index array per array"));
+    }
+
+    #[test]
+    fn test_concat_against_dictionary() {
+        let a: DictionaryArray<Int16Type> = vec![Some("b"), None, Some("d"), Some("b")]
+            .into_iter()
+            .collect();
+        let b: DictionaryArray<Int16Type> = vec!["a", "c", "a"].into_iter().collect();
+        let target = StringArray::from(vec![
+            Some("e"),
+            None,
+            Some("d"),
+            Some("c"),
+            Some("b"),
+            Some("a"),
+        ]);
+
+        let result = concat_against_dictionary::<Int16Type>(&[&a, &b], &target).unwrap();
+        let result = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int16Type>>()
+            .unwrap();
+
+        assert_eq!(
+            result.keys(),
+            &Int16Array::from(vec![
+                Some(4),
+                None,
+                Some(2),
+                Some(4),
+                Some(5),
+                Some(3),
+                Some(5)
+            ])
+        );
+        assert_eq!(result.values().as_ref(), &target as &dyn Array);
+    }
+
+    #[test]
+    fn test_concat_against_dictionary_missing_value() {
+        let a: DictionaryArray<Int16Type> = vec!["a"].into_iter().collect();
+        let b: DictionaryArray<Int16Type> = vec!["a", "z"].into_iter().collect();
+        let target = StringArray::from(vec!["a", "b"]);
+
+        let err = concat_against_dictionary::<Int16Type>(&[&a, &b], &target).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: value at index 1 of the dictionary of arrays[1] is not present in target_values"
+        );
+
+        let err =
+            concat_against_dictionary::<Int16Type>(&[&a], &Int32Array::from(vec![1]))
+                .unwrap_err();
+        assert!(err.to_string().contains("expects arrays of type"));
+    }
+
+    #[test]
+    fn test_concat_fixed_size_list() {
+        let list = |values: Vec<f32>| {
+            let len = values.len() / 3;
+            let data = ArrayData::builder(DataType::FixedSizeList(
+                Box::new(Field::new("item", DataType::Float32, true)),
+                3,
+            ))
+            .len(len)
+            .add_child_data(Float32Array::from(values).data().clone())
+            .build()
+            .unwrap();
+            FixedSizeListArray::from(data)
+        };
+        let a = list(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let b = list(vec![7.0, 8.0, 9.0]);
+
+        let arrays = [&a as &dyn Array, &b];
+        let data = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+        match concat_capacities(&data, 1).unwrap() {
+            Capacities::List(3, Some(values)) => {
+                assert!(matches!(*values, Capacities::Array(9)))
+            }
+            capacities => panic!("unexpected capacities {:?}", capacities),
+        }
+
+        let result = concat(&arrays).unwrap();
+        let result = result
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.value_length(), 3);
+        assert_eq!(
+            result.values().as_ref(),
+            &Float32Array::from(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0])
+                as &dyn Array
+        );
+
+        let c = FixedSizeListArray::from(
+            ArrayData::builder(DataType::FixedSizeList(
+                Box::new(Field::new("item", DataType::Float32, true)),
+                2,
+            ))
+            .len(1)
+            .add_child_data(Float32Array::from(vec![1.0, 2.0]).data().clone())
+            .build()
+            .unwrap(),
+        );
+        let err = concat(&[&a, &c]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: It is not possible to concatenate fixed size list arrays with different sizes: 3 and 2"
+        );
+    }
+
+    #[test]
+    fn test_concat_builder_from_array() {
+        let a = StringArray::from(vec![Some("a"), None]);
+        let b = StringArray::from(vec!["b", "c"]);
+        let c = StringArray::from(vec![None, Some("d")]);
+        let d = StringArray::from(vec!["e"]);
+
+        let array = concat(&[&a, &b]).unwrap();
+        let mut builder = ConcatBuilder::from_array(array);
+        assert_eq!(builder.len(), 4);
+        assert_eq!(builder.data_type(), &DataType::Utf8);
+
+        builder.push(&c).unwrap();
+        builder.push(&d).unwrap();
+        assert!(builder.push(&Int32Array::from(vec![1])).is_err());
+
+        let result = builder.finish().unwrap();
+        let expected = concat(&[&a, &b, &c, &d]).unwrap();
+        assert_eq!(result.as_ref(), expected.as_ref());
+    }
+
+    #[test]
+    fn test_concat_builder_from_array_reuses_buffers() -> Result<()> {
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let b = Int32Array::from(vec![4, 5]);
+        let array = concat(&[&a, &b])?;
+        let values = array.data().buffers()[0].as_ptr();
+        let nulls = array.data().null_buffer().unwrap().as_ptr();
+
+        let mut builder = ConcatBuilder::from_array(array);
+        builder.push(&Int32Array::from(vec![Some(6), None]))?;
+        builder.push(&b)?;
+        let result = builder.finish()?;
+        let expected = Int32Array::from(vec![
+            Some(1),
+            None,
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(6),
+            None,
+            Some(4),
+            Some(5),
+        ]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+        // both fit in the 64 bytes allocated for the first result
+        assert_eq!(result.data().buffers()[0].as_ptr(), values);
+        assert_eq!(result.data().null_buffer().unwrap().as_ptr(), nulls);
+
+        // shared buffers are copied rather than modified
+        let array = concat(&[&a, &b])?;
+        let shared = array.clone();
+        let mut builder = ConcatBuilder::from_array(array);
+        builder.push(&b)?;
+        assert_eq!(builder.finish()?.len(), 7);
+        assert_eq!(shared.len(), 5);
+        assert_eq!(shared.as_ref(), concat(&[&a, &b])?.as_ref());
+
+        let strings = [
+            StringArray::from(vec![Some("hello"), None]),
+            StringArray::from(vec!["world"]),
+            StringArray::from(vec!["!"]),
+        ];
+        let array = concat(&[&strings[0], &strings[1]])?;
+        let values = array.data().buffers()[1].as_ptr();
+        let mut builder = ConcatBuilder::from_array(array);
+        builder.push(&strings[2])?;
+        let result = builder.finish()?;
+        assert_eq!(
+            result.as_ref(),
+            concat(&[&strings[0], &strings[1], &strings[2]])?.as_ref()
+        );
+        assert_eq!(result.data().buffers()[1].as_ptr(), values);
+
+        // bits past the length of a reused bitmap are cleared
+        let booleans = BooleanArray::from(vec![true, true, true, true]);
+        let array = concat(&[booleans.slice(0, 3).as_ref(), &booleans.slice(1, 0)])?;
+        let mut builder = ConcatBuilder::from_array(array);
+        builder.push(&BooleanArray::from(vec![false, true]))?;
+        let result = builder.finish()?;
+        assert_eq!(
+            result.as_ref(),
+            &BooleanArray::from(vec![true, true, true, false, true]) as &dyn Array
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_identical_arrays() {
+        let array: ArrayRef =
+            Arc::new(StringArray::from(vec![Some("a"), None, Some("b")]));
+        let arrays = vec![array.as_ref(); 5];
+
+        let result = concat(&arrays).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            concat_n(array.as_ref(), 5).unwrap().as_ref()
+        );
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(result.len(), 15);
+        for i in 0..5 {
+            assert_eq!(result.value(i * 3), "a");
+            assert!(result.is_null(i * 3 + 1));
+            assert_eq!(result.value(i * 3 + 2), "b");
+        }
+
+        // slices of the same data at different offsets are not identical
+        let result =
+            concat(&[array.slice(0, 2).as_ref(), array.slice(1, 2).as_ref()]).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &StringArray::from(vec![Some("a"), None, None, Some("b")]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn test_concat_intervals() {
+        let year_month: ArrayRef =
+            Arc::new(IntervalYearMonthArray::from(vec![Some(1), None, Some(13)]));
+        let day_time: ArrayRef =
+            Arc::new(IntervalDayTimeArray::from(vec![Some(1), Some(2), None]));
+        let month_day_nano: ArrayRef = Arc::new(IntervalMonthDayNanoArray::from(vec![
+            None,
+            Some(1),
+            Some(i128::MAX),
+        ]));
+
+        for array in [&year_month, &day_time, &month_day_nano] {
+            let result = concat(&[array.as_ref(), array.slice(1, 2).as_ref()]).unwrap();
+            assert_eq!(result.data_type(), array.data_type());
+            assert_eq!(result.len(), 5);
+            assert_eq!(result.slice(0, 3).as_ref(), array.as_ref());
+            assert_eq!(result.slice(3, 2).as_ref(), array.slice(1, 2).as_ref());
+        }
+
+        let err = concat(&[year_month.as_ref(), month_day_nano.as_ref()]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: It is not possible to concatenate interval arrays with different units: YearMonth and MonthDayNano"
+        );
+        let err = concat(&[day_time.as_ref(), year_month.as_ref()]).unwrap_err();
+        assert!(err.to_string().contains("DayTime and YearMonth"));
+    }
+
+    #[test]
+    fn test_concat_with_stats() {
+        let a = StringArray::from(vec![Some("a"), None, None]);
+        let b = StringArray::from(vec!["b", "c"]);
+        let c = StringArray::from(vec![None, Some("d"), None, None]).slice(1, 3);
+
+        let (result, stats) = concat_with_stats(&[&a, &b, c.as_ref()]).unwrap();
+        assert_eq!(
+            stats.null_count,
+            a.null_count() + b.null_count() + c.null_count()
+        );
+        assert_eq!(stats.null_count, result.null_count());
+        assert!(stats.has_nulls);
+
+        let (result, stats) = concat_with_stats(&[&b, &b]).unwrap();
+        assert_eq!(result.null_count(), 0);
+        assert_eq!(stats, ConcatStats::default());
+    }
+
+    #[test]
+    #[cfg(not(feature = "force_validate"))]
+    fn test_concat_large_list_child_offset_overflow() {
+        // zeroed value buffers are lazily allocated, so this is cheap
+        let values = Buffer::from(MutableBuffer::from_len_zeroed(1 << 30));
+        let offsets = Buffer::from_slice_ref(&[0, 1 << 30]);
+        let strings = unsafe {
+            ArrayData::builder(DataType::Utf8)
+                .len(1)
+                .add_buffer(offsets)
+                .add_buffer(values)
+                .build_unchecked()
+        };
+        let list = LargeListArray::from(
+            ArrayData::builder(DataType::LargeList(Box::new(Field::new(
+                "item",
+                DataType::Utf8,
+                true,
+            ))))
+            .len(1)
+            .add_buffer(Buffer::from_slice_ref(&[0i64, 1]))
+            .add_child_data(strings)
+            .build()
+            .unwrap(),
+        );
+
+        // the i64 list offsets fit, but the offsets of the Utf8 child do not
+        let error = concat(&[&list, &list]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Compute error: concat of Utf8 arrays with 2147483648 value bytes would overflow i32 offsets, consider casting to LargeUtf8 first"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "force_validate"))]
+    fn test_concat_lists_autowide() {
+        // zeroed value buffers are lazily allocated, so this is cheap
+        let values = Buffer::from(MutableBuffer::from_len_zeroed(1 << 30));
+        let offsets = Buffer::from_slice_ref(&[0, 1 << 30]);
+        let strings = unsafe {
+            ArrayData::builder(DataType::Utf8)
+                .len(1)
+                .add_buffer(offsets)
+                .add_buffer(values)
+                .build_unchecked()
+        };
+        let field = Field::new("item", DataType::Utf8, true);
+        let list = ListArray::from(
+            ArrayData::builder(DataType::List(Box::new(field)))
+                .len(1)
+                .add_buffer(Buffer::from_slice_ref(&[0i32, 1]))
+                .add_child_data(strings)
+                .build()
+                .unwrap(),
+        );
+        assert!(concat(&[&list, &list]).is_err());
+
+        // the list offsets fit, so only the Utf8 child is widened
+        let array = concat_lists_autowide(&[&list, &list]).unwrap();
+        let expected_type =
+            DataType::List(Box::new(Field::new("item", DataType::LargeUtf8, true)));
+        assert_eq!(array.data_type(), &expected_type);
+        assert_eq!(array.len(), 2);
+        let array = array.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(array.value_offsets(), &[0, 1, 2]);
+        let child = array.values();
+        let child = child.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        assert_eq!(child.value_offsets(), &[0, 1 << 30, 1 << 31]);
+
+        // nothing is widened when nothing overflows
+        let small = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+        ]);
+        let large =
+            LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![
+                Some(2),
+            ])]);
+        let array = concat_lists_autowide(&[&small, &small]).unwrap();
+        assert_eq!(array.as_ref(), concat(&[&small, &small]).unwrap().as_ref());
+        let array = concat_lists_autowide(&[&small, &large]).unwrap();
+        assert_eq!(
+            array.as_ref(),
+            concat_lists_unify_offsets(&[&small, &large])
+                .unwrap()
+                .as_ref()
+        );
+
+        let error =
+            concat_lists_autowide(&[&small, &Int32Array::from(vec![1])]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: concat_lists_autowide requires list arrays, got Int32"
+        );
+    }
+
+    #[test]
+    fn test_concat_utf8_with_byte_ranges() {
+        let a = StringArray::from(vec![Some("hello"), None, Some("arrow")]);
+        let b = StringArray::from(vec![""]);
+        let c = StringArray::from(vec![Some("foo"), Some("bar"), Some("baz")]);
+        let c = c.slice(1, 2);
+        let c = c.as_any().downcast_ref::<StringArray>().unwrap();
+        let empty = StringArray::from(Vec::<&str>::new());
+
+        let (array, ranges) = concat_utf8_with_byte_ranges(&[&a, &b, &empty, c]).unwrap();
+        assert_eq!(
+            array,
+            StringArray::from(vec![
+                Some("hello"),
+                None,
+                Some("arrow"),
+                Some(""),
+                Some("bar"),
+                Some("baz")
+            ])
+        );
+        assert_eq!(ranges, vec![0..10, 10..10, 10..10, 10..16]);
+        // the ranges tile the whole value buffer without gaps
+        assert!(ranges.windows(2).all(|r| r[0].end == r[1].start));
+        assert_eq!(ranges.last().unwrap().end, array.value_data().len());
+        assert_eq!(&array.value_data()[ranges[3].clone()], b"barbaz");
+
+        // a single non empty input is not copied, so its ranges are relative
+        // to the value buffer it shares with the input
+        let (array, ranges) = concat_utf8_with_byte_ranges(&[&empty, c]).unwrap();
+        assert_eq!(ranges, vec![3..3, 3..9]);
+        assert_eq!(&array.value_data()[ranges[1].clone()], b"barbaz");
+
+        let a = LargeStringArray::from(vec!["a", "bc"]);
+        let (array, ranges) = concat_utf8_with_byte_ranges(&[&a, &a]).unwrap();
+        assert_eq!(array, LargeStringArray::from(vec!["a", "bc", "a", "bc"]));
+        assert_eq!(ranges, vec![0..3, 3..6]);
+    }
+
+    #[test]
+    fn test_concat_with_capacity() {
+        let a = Int64Array::from(vec![Some(1), None]);
+        let b = Int64Array::from(vec![3]);
+
+        let result = concat_with_capacity(&[&a, &b], 1000).unwrap();
+        assert_eq!(result.as_ref(), concat(&[&a, &b]).unwrap().as_ref());
+        assert!(result.data().buffers()[0].capacity() >= 1000 * 8);
+        let nulls = result.data().null_buffer().unwrap();
+        assert!(nulls.capacity() >= bit_util::ceil(1000, 8));
+
+        let a = StringArray::from(vec!["a", "b"]);
+        let result = concat_with_capacity(&[&a], 1000).unwrap();
+        assert_eq!(result.as_ref(), &a as &dyn Array);
+        // offsets have one more slot than the array
+        assert!(result.data().buffers()[0].capacity() >= 1001 * 4);
+
+        // smaller capacities are clamped up
+        let result = concat_with_capacity(&[&a, &a], 1).unwrap();
+        assert_eq!(result.len(), 4);
+    }
+
+    #[test]
+    fn test_concat_with_capacity_null_arrays() {
+        let nulls = NullArray::new(2);
+        let a = Int32Array::from(vec![Some(1), None]);
+
+        let result = concat_with_capacity(&[&nulls, &a], 100).unwrap();
+        let expected = Int32Array::from(vec![None, None, Some(1), None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+        assert!(result.data().buffers()[0].capacity() >= 100 * 4);
+    }
+
+    #[test]
+    fn test_concat_sliced_dictionaries() {
+        let a: DictionaryArray<Int32Type> =
+            vec![Some("a"), Some("b"), None, Some("c"), Some("a")]
+                .into_iter()
+                .collect();
+        let b: DictionaryArray<Int32Type> = vec![Some("d"), Some("c"), Some("e"), None]
+            .into_iter()
+            .collect();
+        let a_sliced = a.slice(1, 3);
+        let b_sliced = b.slice(1, 3);
+
+        let decode = |array: &ArrayRef| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<Int32Type>>()
+                .unwrap();
+            let values = array.values();
+            let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+            array
+                .keys()
+                .iter()
+                .map(|key| key.map(|key| values.value(key as usize).to_string()))
+                .collect::<Vec<_>>()
+        };
+
+        let result = concat(&[a_sliced.as_ref(), b_sliced.as_ref()]).unwrap();
+        let a_expected = StringArray::from(vec![Some("b"), None, Some("c")]);
+        let b_expected = StringArray::from(vec![Some("c"), Some("e"), None]);
+        let expected = concat(&[&a_expected, &b_expected]).unwrap();
+        let expected = expected.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(
+            decode(&result),
+            expected
+                .iter()
+                .map(|v| v.map(|v| v.to_string()))
+                .collect::<Vec<_>>()
+        );
+
+        // sliced inputs sharing a dictionary
+        let result = concat(&[a_sliced.as_ref(), a.slice(3, 2).as_ref()]).unwrap();
+        assert_eq!(
+            decode(&result),
+            vec![Some("b"), None, Some("c"), Some("c"), Some("a")]
+                .into_iter()
+                .map(|v| v.map(|v| v.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_dictionary_concat_builder() {
+        let mut builder = DictionaryConcatBuilder::<Int16Type>::new(DataType::Utf8);
+        assert!(builder.is_empty());
+
+        builder
+            .push_values(&StringArray::from(vec!["a", "b"]))
+            .unwrap();
+        builder
+            .push_values(&StringArray::from(vec![Some("b"), Some("c"), None]))
+            .unwrap();
+        builder
+            .push_values(&StringArray::from(vec!["c", "a", "d", "d"]).slice(1, 3))
+            .unwrap();
+        assert_eq!(builder.len(), 8);
+        assert!(builder.push_values(&Int32Array::from(vec![1])).is_err());
+
+        let result = builder.finish().unwrap();
+        let result = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int16Type>>()
+            .unwrap();
+        assert_eq!(
+            result.values().as_ref(),
+            &StringArray::from(vec!["a", "b", "c", "d"]) as &dyn Array
+        );
+        assert_eq!(
+            result.keys(),
+            &Int16Array::from(vec![
+                Some(0),
+                Some(1),
+                Some(1),
+                Some(2),
+                None,
+                Some(0),
+                Some(3),
+                Some(3)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_dictionary_concat_builder_merges_values() {
+        let mut builder = DictionaryConcatBuilder::<Int16Type>::new(DataType::Int32);
+        let mut expected = vec![];
+        for i in 0..50 {
+            // values interleaved with, and repeating, those of previous pushes
+            let values = [i * 7 % 50, 100 - i, i * 7 % 50, 49 - i];
+            builder
+                .push_values(&Int32Array::from(values.to_vec()))
+                .unwrap();
+            expected.extend(values);
+
+            // the index stays sorted by value
+            let values = builder.values();
+            let values = values.as_any().downcast_ref::<Int32Array>().unwrap();
+            assert_eq!(builder.sorted.len(), values.len());
+            assert!(builder
+                .sorted
+                .windows(2)
+                .all(|w| values.value(w[0]) < values.value(w[1])));
+        }
+        let values = builder.values().data().buffers()[0].as_ptr();
+        builder.push_values(&Int32Array::from(vec![1000])).unwrap();
+        // the new value fits in the capacity of the values buffer
+        assert_eq!(builder.values().data().buffers()[0].as_ptr(), values);
+        expected.push(1000);
+
+        let result = builder.finish().unwrap();
+        let result = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int16Type>>()
+            .unwrap();
+        let values = result.values();
+        let values = values.as_any().downcast_ref::<Int32Array>().unwrap();
+        let decoded = result
+            .keys()
+            .values()
+            .iter()
+            .map(|k| values.value(*k as usize))
+            .collect::<Vec<_>>();
+        assert_eq!(decoded, expected);
+        let mut distinct = expected.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        assert_eq!(values.len(), distinct.len());
+    }
+
+    #[test]
+    fn test_dictionary_concat_builder_overflow() {
+        let mut builder = DictionaryConcatBuilder::<Int8Type>::new(DataType::Int32);
+        builder
+            .push_values(&Int32Array::from_iter_values(0..100))
+            .unwrap();
+        let err = builder
+            .push_values(&Int32Array::from_iter_values(50..150))
+            .unwrap_err();
+        assert!(matches!(err, ArrowError::DictionaryKeyOverflowError));
+
+        builder
+            .push_values(&Int32Array::from_iter_values(50..128))
+            .unwrap();
+        assert_eq!(builder.values().len(), 128);
+    }
+
+    #[test]
+    fn test_concat_empty_variable_width() {
+        let empty = StringArray::from(Vec::<&str>::new());
+        let sliced = StringArray::from(vec!["a", "b"]).slice(1, 0);
+        let builder_empty = StringBuilder::new(0).finish();
+        let new_empty = new_empty_array(&DataType::Utf8);
+        let arrays = [
+            &empty as &dyn Array,
+            sliced.as_ref(),
+            &builder_empty,
+            new_empty.as_ref(),
+        ];
+
+        for i in 0..arrays.len() {
+            for j in 0..arrays.len() {
+                let result = concat(&[arrays[i], arrays[j]]).unwrap();
+                let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+                assert_eq!(result.len(), 0);
+                assert_eq!(result.value_offsets().len(), 1);
+                result.data().validate_full().unwrap();
+            }
+        }
+
+        let empty = LargeStringArray::from(Vec::<&str>::new());
+        let result = concat(&[&empty, &empty]).unwrap();
+        let result = result.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        assert_eq!(result.value_offsets(), &[0]);
+
+        let result = concat_with_capacity(&[&empty, &empty], 0).unwrap();
+        let result = result.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        assert_eq!(result.value_offsets(), &[0]);
+    }
+
+    #[test]
+    fn test_concat_slice() {
+        let a = StringArray::from(vec![Some("a"), None, Some("c"), Some("d"), None]);
+        let b = StringArray::from(vec!["f", "g", "h", "i", "j"]);
+        let c = StringArray::from(vec![None, Some("l"), Some("m"), None, Some("o")]);
+        let arrays = [&a as &dyn Array, &b, &c];
+        let full = concat(&arrays).unwrap();
+
+        for (offset, length) in
+            [(7, 5), (0, 15), (0, 3), (5, 5), (3, 10), (14, 1), (6, 0)]
+        {
+            let result = concat_slice(&arrays, offset, length).unwrap();
+            assert_eq!(
+                result.as_ref(),
+                full.slice(offset, length).as_ref(),
+                "offset {} length {}",
+                offset,
+                length
+            );
+        }
+
+        let err = concat_slice(&arrays, 10, 6).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: concat_slice of 6 rows at offset 10 is out of bounds of the 15 rows of arrays"
+        );
+        assert!(concat_slice(&arrays, usize::MAX, 2).is_err());
     }
 
-    let lengths = arrays.iter().map(|array| array.len()).collect::<Vec<_>>();
-    let capacity = lengths.iter().sum();
+    #[test]
+    fn test_concat_batches_ordered_dictionaries() {
+        let dictionary = |values: Vec<Option<&str>>| -> ArrayRef {
+            Arc::new(values.into_iter().collect::<DictionaryArray<Int8Type>>())
+        };
+        let data_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new_dict("ordered", data_type.clone(), true, 0, true),
+            Field::new_dict("unordered", data_type, true, 1, false),
+        ]));
 
-    let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+        let a = dictionary(vec![Some("b"), Some("d"), None, Some("b")]);
+        let b = dictionary(vec![Some("c"), Some("a"), None]);
+        let batch_1 = RecordBatch::try_new(schema.clone(), vec![a.clone(), a]).unwrap();
+        let batch_2 = RecordBatch::try_new(schema.clone(), vec![b.clone(), b]).unwrap();
 
-    let mut mutable = match arrays[0].data_type() {
-        DataType::Utf8 => {
-            let str_values_size = compute_str_values_length::<i32>(&arrays);
-            MutableArrayData::with_capacities(
-                arrays,
-                false,
-                Capacities::Binary(capacity, Some(str_values_size)),
-            )
-        }
-        DataType::LargeUtf8 => {
-            let str_values_size = compute_str_values_length::<i64>(&arrays);
-            MutableArrayData::with_capacities(
-                arrays,
-                false,
-                Capacities::Binary(capacity, Some(str_values_size)),
-            )
-        }
-        _ => MutableArrayData::new(arrays, false, capacity),
-    };
+        let result = concat_batches(&schema, &[batch_1, batch_2]).unwrap();
+        let expected = vec![
+            Some("b"),
+            Some("d"),
+            None,
+            Some("b"),
+            Some("c"),
+            Some("a"),
+            None,
+        ];
+        for (i, sorted) in [(0, true), (1, false)] {
+            let column = result
+                .column(i)
+                .as_any()
+                .downcast_ref::<DictionaryArray<Int8Type>>()
+                .unwrap();
+            assert!(!column.is_ordered());
+            let values = column.values();
+            let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+            let decoded = column
+                .keys()
+                .iter()
+                .map(|key| key.map(|key| values.value(key as usize)))
+                .collect::<Vec<_>>();
+            assert_eq!(decoded, expected);
 
-    for (i, len) in lengths.iter().enumerate() {
-        mutable.extend(i, 0, *len)
+            let values = values.iter().map(|v| v.unwrap()).collect::<Vec<_>>();
+            let mut sorted_values = values.clone();
+            sorted_values.sort_unstable();
+            assert_eq!(values == sorted_values, sorted, "column {}", i);
+        }
     }
 
-    Ok(make_array(mutable.freeze()))
-}
+    #[test]
+    fn test_concat_batches_ordered_non_dictionary() {
+        // only dictionary fields are ordered, whatever their flag
+        let schema = Arc::new(Schema::new(vec![Field::new_dict(
+            "a",
+            DataType::Int32,
+            false,
+            0,
+            true,
+        )]));
+        let batch_1 = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![3, 1]))],
+        )
+        .unwrap();
+        let batch_2 = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![2]))],
+        )
+        .unwrap();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::datatypes::*;
-    use std::sync::Arc;
+        let result = concat_batches(&schema, &[batch_1, batch_2]).unwrap();
+        assert_eq!(
+            result.column(0).as_ref(),
+            &Int32Array::from(vec![3, 1, 2]) as &dyn Array
+        );
+
+        let array: ArrayRef = Arc::new(Int32Array::from(vec![3, 1]));
+        assert!(sort_dictionary(&array).unwrap().data().ptr_eq(array.data()));
+    }
 
     #[test]
-    fn test_concat_empty_vec() {
-        let re = concat(&[]);
-        assert!(re.is_err());
+    fn test_concat_partition() {
+        let a = StringArray::from(vec![Some("a"), None, Some("c"), Some("d"), Some("e")]);
+        let b = StringArray::from(vec!["f", "g", "h"]);
+        let groups_a = Int32Array::from(vec![Some(0), Some(0), Some(1), None, Some(0)]);
+        let groups_b = Int32Array::from(vec![1, 1, 0]);
+
+        let partitions = concat_partition(&[&a, &b], &[&groups_a, &groups_b], 3).unwrap();
+        assert_eq!(partitions.len(), 3);
+        assert_eq!(
+            partitions[0].as_ref(),
+            &StringArray::from(vec![Some("a"), None, Some("e"), Some("h")]) as &dyn Array
+        );
+        assert_eq!(
+            partitions[1].as_ref(),
+            &StringArray::from(vec!["c", "f", "g"]) as &dyn Array
+        );
+        assert_eq!(partitions[2].len(), 0);
+        assert_eq!(partitions[2].data_type(), &DataType::Utf8);
     }
 
     #[test]
-    fn test_concat_one_element_vec() -> Result<()> {
-        let arr = Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-            Some(-1),
-            Some(2),
-            None,
-        ])) as ArrayRef;
-        let result = concat(&[arr.as_ref()])?;
+    fn test_concat_partition_invalid() {
+        let a = Int32Array::from(vec![1, 2]);
+
+        let err =
+            concat_partition(&[&a], &[&Int32Array::from(vec![0, 2])], 2).unwrap_err();
         assert_eq!(
-            &arr, &result,
-            "concatenating single element array gives back the same result"
+            err.to_string(),
+            "Invalid argument error: group id 2 is out of bounds of 2 groups"
+        );
+        let err =
+            concat_partition(&[&a], &[&Int32Array::from(vec![-1, 0])], 2).unwrap_err();
+        assert!(err.to_string().contains("group id -1"));
+
+        let err = concat_partition(&[&a], &[&Int32Array::from(vec![0])], 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: groups[0] has length 1 but arrays[0] has length 2"
+        );
+        assert!(
+            concat_partition(&[&a, &a], &[&Int32Array::from(vec![0, 0])], 2).is_err()
         );
-        Ok(())
     }
 
     #[test]
-    fn test_concat_incompatible_datatypes() {
-        let re = concat(&[
-            &PrimitiveArray::<Int64Type>::from(vec![Some(-1), Some(2), None]),
-            &StringArray::from(vec![Some("hello"), Some("bar"), Some("world")]),
-        ]);
-        assert!(re.is_err());
+    fn test_string_values_byte_size() {
+        let a = StringArray::from(vec![Some("hello"), None, Some("world")]);
+        let b = StringArray::from(vec!["a", "bc", "def", "ghij"]);
+        let slices = [b.slice(1, 2), b.slice(3, 1), b.slice(2, 0)];
+        let arrays = [
+            &a as &dyn Array,
+            slices[0].as_ref(),
+            slices[1].as_ref(),
+            slices[2].as_ref(),
+        ];
+        let data = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
+        let size = string_values_byte_size::<i32>(&arrays).unwrap();
+        assert_eq!(size, compute_binary_values_length::<i32>(&data));
+        assert_eq!(size, 19);
+
+        let c = LargeBinaryArray::from(vec![b"ab".as_ref(), b"", b"cde"]);
+        let size = string_values_byte_size::<i64>(&[c.slice(1, 2).as_ref()]).unwrap();
+        assert_eq!(size, 3);
+
+        let err = string_values_byte_size::<i32>(&[&a, &c]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: string_values_byte_size expects Utf8 or Binary arrays, got LargeBinary"
+        );
+        assert!(string_values_byte_size::<i64>(&[&a]).is_err());
     }
 
     #[test]
-    fn test_concat_string_arrays() -> Result<()> {
-        let arr = concat(&[
-            &StringArray::from(vec!["hello", "world"]),
-            &StringArray::from(vec!["2", "3", "4"]),
-            &StringArray::from(vec![Some("foo"), Some("bar"), None, Some("baz")]),
-        ])?;
+    fn test_concat_mixed_nulls() {
+        // covers the small fixed width, generic fixed width and variable width paths
+        for len in [3, 100] {
+            let no_nulls = Int64Array::from_iter_values(0..len);
+            let nulls = Int64Array::from_iter((0..len).map(|v| (v % 3 != 0).then(|| v)));
+            assert_eq!(no_nulls.data().null_buffer(), None);
 
-        let expected_output = Arc::new(StringArray::from(vec![
-            Some("hello"),
-            Some("world"),
-            Some("2"),
-            Some("3"),
-            Some("4"),
-            Some("foo"),
-            Some("bar"),
-            None,
-            Some("baz"),
-        ])) as ArrayRef;
+            for arrays in [[&no_nulls, &nulls], [&nulls, &no_nulls]] {
+                let result = concat(&[arrays[0], arrays[1]]).unwrap();
+                assert_eq!(result.null_count(), nulls.null_count());
+                let expected = arrays[0]
+                    .iter()
+                    .chain(arrays[1].iter())
+                    .collect::<Int64Array>();
+                assert_eq!(result.as_ref(), &expected as &dyn Array);
+            }
 
-        assert_eq!(&arr, &expected_output);
+            let no_nulls = StringArray::from_iter_values((0..len).map(|v| v.to_string()));
+            let nulls = StringArray::from_iter(
+                (0..len).map(|v| (v % 3 != 0).then(|| v.to_string())),
+            );
+            let result = concat(&[&no_nulls, &nulls]).unwrap();
+            assert_eq!(result.null_count(), nulls.null_count());
+            let expected = no_nulls.iter().chain(nulls.iter()).collect::<StringArray>();
+            assert_eq!(result.as_ref(), &expected as &dyn Array);
+        }
 
-        Ok(())
+        let no_nulls = Int64Array::from_iter_values(0..100);
+        let result = concat(&[&no_nulls, &no_nulls.slice(10, 20)]).unwrap();
+        assert_eq!(result.data().null_buffer(), None);
     }
 
     #[test]
-    fn test_concat_primitive_arrays() -> Result<()> {
-        let arr = concat(&[
-            &PrimitiveArray::<Int64Type>::from(vec![
-                Some(-1),
-                Some(-1),
-                Some(2),
-                None,
-                None,
-            ]),
-            &PrimitiveArray::<Int64Type>::from(vec![
-                Some(101),
-                Some(102),
-                Some(103),
-                None,
-            ]),
-            &PrimitiveArray::<Int64Type>::from(vec![Some(256), Some(512), Some(1024)]),
-        ])?;
+    fn test_concat_batches_extension_metadata() -> Result<()> {
+        let extension_field = |name: &str| {
+            let metadata = BTreeMap::from([
+                ("ARROW:extension:name".to_string(), name.to_string()),
+                ("ARROW:extension:metadata".to_string(), "{}".to_string()),
+            ]);
+            Field::new("geometry", DataType::Binary, false).with_metadata(Some(metadata))
+        };
+        let batch = |field: Field, values: Vec<&[u8]>| {
+            let schema = Arc::new(Schema::new(vec![field]));
+            RecordBatch::try_new(schema, vec![Arc::new(BinaryArray::from(values))])
+        };
+        let batch_1 = batch(extension_field("geoarrow.wkb"), vec![b"\x01", b"\x02"])?;
+        let batch_2 = batch(extension_field("geoarrow.wkb"), vec![b"\x03"])?;
 
-        let expected_output = Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-            Some(-1),
-            Some(-1),
-            Some(2),
-            None,
-            None,
-            Some(101),
-            Some(102),
-            Some(103),
-            None,
-            Some(256),
-            Some(512),
-            Some(1024),
-        ])) as ArrayRef;
+        let schema = batch_1.schema();
+        let result = concat_batches(&schema, &[batch_1.clone(), batch_2.clone()])?;
+        assert_eq!(result.schema(), schema);
+        assert_eq!(result.num_rows(), 3);
 
-        assert_eq!(&arr, &expected_output);
+        let result = concat_batches_unify_nullability(&[batch_1.clone(), batch_2])?;
+        assert_eq!(
+            result.schema().field(0).metadata(),
+            schema.field(0).metadata()
+        );
+
+        let other = batch(extension_field("geoarrow.wkt"), vec![b"\x03"])?;
+        assert!(concat_batches(&schema, &[batch_1.clone(), other.clone()]).is_err());
+        assert!(
+            concat_batches_unify_nullability(&[batch_1.clone(), other.clone()]).is_err()
+        );
+        let error = concat_batches_union_schema(&[batch_1.clone(), other]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: batches[1] field geometry has an extension type which conflicts with that of earlier batches."
+        );
 
+        let plain = batch(
+            Field::new("geometry", DataType::Binary, true),
+            vec![b"\x03"],
+        )?;
+        assert!(
+            concat_batches_unify_nullability(&[batch_1.clone(), plain.clone()]).is_err()
+        );
+        assert!(concat_batches_union_schema(&[batch_1, plain]).is_err());
         Ok(())
     }
 
     #[test]
-    fn test_concat_primitive_array_slices() -> Result<()> {
-        let input_1 = PrimitiveArray::<Int64Type>::from(vec![
-            Some(-1),
-            Some(-1),
-            Some(2),
-            None,
-            None,
-        ])
-        .slice(1, 3);
+    fn test_concat_boolean_unaligned() {
+        let values = (0..200)
+            .map(|i| (i % 7 != 0).then(|| i % 3 == 0))
+            .collect::<BooleanArray>();
+        let no_nulls = (0..130).map(|i| Some(i % 5 < 2)).collect::<BooleanArray>();
 
-        let input_2 = PrimitiveArray::<Int64Type>::from(vec![
-            Some(101),
-            Some(102),
-            Some(103),
-            None,
-        ])
-        .slice(1, 3);
-        let arr = concat(&[input_1.as_ref(), input_2.as_ref()])?;
+        for (offset_1, len_1, offset_2, len_2) in [
+            (0, 200, 0, 130),
+            (3, 61, 5, 100),
+            (1, 7, 9, 1),
+            (13, 150, 64, 66),
+            (64, 64, 0, 13),
+        ] {
+            let a = values.slice(offset_1, len_1);
+            let b = no_nulls.slice(offset_2, len_2);
+            let c = values.slice(offset_2, len_2);
+            let arrays = [a.as_ref(), b.as_ref(), c.as_ref()];
+            let data = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
 
-        let expected_output = Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-            Some(-1),
-            Some(2),
-            None,
-            Some(102),
-            Some(103),
-            None,
-        ])) as ArrayRef;
+            let result = make_array(concat_boolean(&data));
+            result.data().validate_full().unwrap();
 
-        assert_eq!(&arr, &expected_output);
+            // the generic path through MutableArrayData
+            let mut mutable = MutableArrayData::new(data.clone(), false, 0);
+            for (i, array) in arrays.iter().enumerate() {
+                mutable.extend(i, 0, array.len());
+            }
+            let expected = make_array(mutable.freeze());
+            assert_eq!(result.as_ref(), expected.as_ref());
+            assert_eq!(concat(&arrays).unwrap().as_ref(), expected.as_ref());
+        }
+    }
 
-        Ok(())
+    #[test]
+    fn test_concat_opt() {
+        let a = StringArray::from(vec![Some("a"), None]);
+        let b = StringArray::from(vec!["b", "c"]);
+
+        let result = concat_opt(&[None, Some(&a), None, Some(&b), None]).unwrap();
+        assert_eq!(result.as_ref(), concat(&[&a, &b]).unwrap().as_ref());
+
+        let result = concat_opt(&[None, Some(&b)]).unwrap();
+        assert_eq!(result.as_ref(), &b as &dyn Array);
+
+        let err = concat_opt(&[None, None]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: concat requires input of at least one array"
+        );
+        assert!(concat_opt(&[]).is_err());
     }
 
     #[test]
-    fn test_concat_boolean_primitive_arrays() -> Result<()> {
-        let arr = concat(&[
-            &BooleanArray::from(vec![
-                Some(true),
-                Some(true),
-                Some(false),
-                None,
-                None,
-                Some(false),
-            ]),
-            &BooleanArray::from(vec![None, Some(false), Some(true), Some(false)]),
-        ])?;
+    fn test_concat_rev() {
+        let a = StringArray::from(vec![Some("a"), None]);
+        let b = StringArray::from(vec!["b", "c", "d"]).slice(1, 2);
+        let c = StringArray::from(vec![Some("e")]);
 
-        let expected_output = Arc::new(BooleanArray::from(vec![
-            Some(true),
-            Some(true),
-            Some(false),
-            None,
-            None,
-            Some(false),
-            None,
-            Some(false),
-            Some(true),
-            Some(false),
-        ])) as ArrayRef;
+        let result = concat_rev(&[&a, b.as_ref(), &c]).unwrap();
+        let expected = concat(&[&c, b.as_ref(), &a]).unwrap();
+        assert_eq!(result.as_ref(), expected.as_ref());
 
-        assert_eq!(&arr, &expected_output);
+        let result = concat_rev(&[&a]).unwrap();
+        assert_eq!(result.as_ref(), &a as &dyn Array);
 
-        Ok(())
+        assert!(concat_rev(&[&a, &Int32Array::from(vec![1])]).is_err());
+        assert!(concat_rev(&[]).is_err());
     }
 
     #[test]
-    fn test_concat_primitive_list_arrays() -> Result<()> {
-        let list1 = vec![
-            Some(vec![Some(-1), Some(-1), Some(2), None, None]),
-            Some(vec![]),
-            None,
-            Some(vec![Some(10)]),
-        ];
-        let list1_array =
-            ListArray::from_iter_primitive::<Int64Type, _, _>(list1.clone());
+    fn test_concat_rev_null_arrays() {
+        let nulls = NullArray::new(2);
+        let a = Int32Array::from(vec![1, 2]);
 
-        let list2 = vec![
-            None,
-            Some(vec![Some(100), None, Some(101)]),
-            Some(vec![Some(102)]),
-        ];
-        let list2_array =
-            ListArray::from_iter_primitive::<Int64Type, _, _>(list2.clone());
+        let result = concat_rev(&[&nulls, &a]).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(2), None, None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
 
-        let list3 = vec![Some(vec![Some(1000), Some(1001)])];
-        let list3_array =
-            ListArray::from_iter_primitive::<Int64Type, _, _>(list3.clone());
+    #[test]
+    fn test_concat_output_alignment() {
+        use crate::alloc::ALIGNMENT;
 
-        let array_result = concat(&[&list1_array, &list2_array, &list3_array])?;
+        let is_aligned = |array: &ArrayRef| {
+            array
+                .data()
+                .buffers()
+                .iter()
+                .chain(array.data().null_buffer())
+                .all(|buffer| buffer.as_ptr() as usize % ALIGNMENT == 0)
+        };
 
-        let expected = list1
-            .into_iter()
-            .chain(list2.into_iter())
-            .chain(list3.into_iter());
-        let array_expected = ListArray::from_iter_primitive::<Int64Type, _, _>(expected);
+        // buffers aligned for their type, but not to ALIGNMENT
+        let values = Buffer::from_slice_ref(&(0..41).collect::<Vec<i64>>()).slice(8);
+        assert_ne!(values.as_ptr() as usize % ALIGNMENT, 0);
+        let unaligned_ints = Int64Array::from(
+            ArrayData::builder(DataType::Int64)
+                .len(40)
+                .add_buffer(values)
+                .build()
+                .unwrap(),
+        );
+        let offsets = Buffer::from_slice_ref(&[0i32, 0, 1, 3, 6]).slice(4);
+        let values = Buffer::from_slice_ref(b"_abcdef").slice(1);
+        let unaligned_strings = StringArray::from(
+            ArrayData::builder(DataType::Utf8)
+                .len(3)
+                .add_buffer(offsets)
+                .add_buffer(values)
+                .build()
+                .unwrap(),
+        );
 
-        assert_eq!(array_result.as_ref(), &array_expected as &dyn Array);
+        let ints = Int64Array::from_iter((0..100).map(|v| (v % 2 == 0).then(|| v)));
+        let small_ints = Int64Array::from(vec![Some(1), None]);
+        let strings = StringArray::from(vec![Some("x"), None]);
+        for (unaligned, other) in [
+            (unaligned_ints.slice(0, 40), Arc::new(ints) as ArrayRef),
+            (
+                unaligned_ints.slice(3, 10),
+                Arc::new(small_ints) as ArrayRef,
+            ),
+            (unaligned_strings.slice(0, 3), Arc::new(strings) as ArrayRef),
+        ] {
+            for arrays in [[&unaligned, &other], [&other, &unaligned]] {
+                let result = concat(&[arrays[0].as_ref(), arrays[1].as_ref()]).unwrap();
+                assert!(is_aligned(&result));
+                result.data().validate_full().unwrap();
+                assert_eq!(
+                    result.slice(0, arrays[0].len()).as_ref(),
+                    arrays[0].as_ref()
+                );
+                assert_eq!(
+                    result.slice(arrays[0].len(), arrays[1].len()).as_ref(),
+                    arrays[1].as_ref()
+                );
+            }
+        }
+    }
 
-        Ok(())
+    #[test]
+    fn test_concat_len_overflow() {
+        let array = Int32Array::from(vec![1, 2]);
+        let data = array.data();
+        assert_eq!(concat_len(&[data, data], 3).unwrap(), 12);
+
+        // stand in for inputs whose lengths sum past usize::MAX
+        let error = concat_len(&[data, data], usize::MAX).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Compute error: concat of 2 arrays repeated {} times would overflow usize length",
+                usize::MAX
+            )
+        );
+        assert!(concat_capacities(&[data], usize::MAX).is_err());
+        assert!(concat_repeated(data, usize::MAX).is_err());
     }
 
     #[test]
-    fn test_concat_struct_arrays() -> Result<()> {
-        let field = Field::new("field", DataType::Int64, true);
-        let input_primitive_1: ArrayRef =
-            Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-                Some(-1),
-                Some(-1),
-                Some(2),
-                None,
-                None,
-            ]));
-        let input_struct_1 = StructArray::from(vec![(field.clone(), input_primitive_1)]);
+    fn test_concat_capacities_large_binary_overflow() {
+        let array = LargeStringArray::from(vec!["abcdefgh"]);
+        let data = array.data();
+        // the length fits in usize, but the value bytes do not
+        let repeat = usize::MAX / 2;
+        assert!(concat_len(&[data], repeat).is_ok());
 
-        let input_primitive_2: ArrayRef =
-            Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-                Some(101),
-                Some(102),
-                Some(103),
-                None,
-            ]));
-        let input_struct_2 = StructArray::from(vec![(field.clone(), input_primitive_2)]);
+        let error = concat_capacities(&[data], repeat).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Compute error: concat of 1 arrays repeated {} times would overflow usize length",
+                repeat
+            )
+        );
+        assert!(concat_n(&array, repeat).is_err());
+    }
+
+    #[test]
+    fn test_concat_to_batch() -> Result<()> {
+        let batch = concat_to_batch(
+            &[
+                &StringArray::from(vec!["a", "b"]),
+                &StringArray::from(vec!["c"]),
+            ],
+            "letters",
+        )?;
+        assert_eq!(batch.num_columns(), 1);
+        assert_eq!(batch.num_rows(), 3);
+        let field = batch.schema().field(0).clone();
+        assert_eq!(field.name(), "letters");
+        assert_eq!(field.data_type(), &DataType::Utf8);
+        assert!(!field.is_nullable());
+        assert_eq!(
+            batch.column(0).as_ref(),
+            &StringArray::from(vec!["a", "b", "c"]) as &dyn Array
+        );
+
+        let batch = concat_to_batch(
+            &[
+                &Int64Array::from(vec![Some(1), None]),
+                &Int64Array::from(vec![3]),
+            ],
+            "numbers",
+        )?;
+        assert_eq!(batch.num_rows(), 3);
+        assert!(batch.schema().field(0).is_nullable());
+
+        let error = concat_to_batch(&[], "empty").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Compute error: concat requires input of at least one array"
+        );
+        Ok(())
+    }
 
-        let input_primitive_3: ArrayRef =
-            Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-                Some(256),
-                Some(512),
-                Some(1024),
-            ]));
-        let input_struct_3 = StructArray::from(vec![(field, input_primitive_3)]);
+    #[test]
+    fn test_concat_with_metadata_policy() -> Result<()> {
+        let metadata = |value: &str| {
+            let metadata: BTreeMap<String, String> =
+                [("k".to_string(), value.to_string())].into_iter().collect();
+            Some(metadata)
+        };
+        let item = Field::new("item", DataType::Int32, true);
+        let list_array = |item: Field, values: Vec<i32>| {
+            let len = values.len() as i32;
+            let data = ArrayData::builder(DataType::List(Box::new(item)))
+                .len(1)
+                .add_buffer(Buffer::from_slice_ref(&[0, len]))
+                .add_child_data(Int32Array::from(values).data().clone())
+                .build()
+                .unwrap();
+            ListArray::from(data)
+        };
+        let struct_array = |item: Field, values: Vec<i32>| {
+            StructArray::from(vec![(
+                Field::new("list", DataType::List(Box::new(item.clone())), true),
+                Arc::new(list_array(item, values)) as ArrayRef,
+            )])
+        };
+        let first = struct_array(item.clone().with_metadata(metadata("1")), vec![1, 2]);
+        let second = struct_array(item.clone().with_metadata(metadata("2")), vec![3]);
+        let third = struct_array(item, vec![]);
+        let arrays: [&dyn Array; 3] = [&first, &second, &third];
 
-        let arr = concat(&[&input_struct_1, &input_struct_2, &input_struct_3])?;
+        let error = concat_with_metadata_policy(&arrays, MetadataPolicy::RequireEqual)
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("Invalid argument error: It is not possible to concatenate struct arrays with different fields"),
+            "{}",
+            error
+        );
+        assert_eq!(
+            concat_with_metadata_policy(&[&first, &first], MetadataPolicy::RequireEqual)?
+                .len(),
+            2
+        );
 
-        let expected_primitive_output = Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-            Some(-1),
-            Some(-1),
-            Some(2),
-            None,
-            None,
-            Some(101),
-            Some(102),
-            Some(103),
-            None,
-            Some(256),
-            Some(512),
-            Some(1024),
-        ])) as ArrayRef;
+        let array = concat_with_metadata_policy(&arrays, MetadataPolicy::KeepFirst)?;
+        assert_eq!(array.data_type(), first.data_type());
+        let keep_first = array.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(keep_first.len(), 3);
+        assert_eq!(
+            keep_first.column(0).data().child_data()[0],
+            *Int32Array::from(vec![1, 2, 3]).data()
+        );
 
-        let actual_primitive = arr
-            .as_any()
-            .downcast_ref::<StructArray>()
-            .unwrap()
-            .column(0);
-        assert_eq!(actual_primitive, &expected_primitive_output);
+        let array = concat_with_metadata_policy(&arrays, MetadataPolicy::Drop)?;
+        assert_eq!(array.data_type(), third.data_type());
+        let dropped = array.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(
+            dropped.column(0).data().child_data()[0],
+            *Int32Array::from(vec![1, 2, 3]).data()
+        );
 
+        // data types differing by more than metadata are still rejected
+        let other = Int32Array::from(vec![1]);
+        let error = concat_with_metadata_policy(&[&first, &other], MetadataPolicy::Drop)
+            .unwrap_err();
+        assert!(
+            error.to_string().contains(
+                "It is not possible to concatenate arrays of different data types"
+            ),
+            "{}",
+            error
+        );
         Ok(())
     }
 
     #[test]
-    fn test_concat_struct_array_slices() -> Result<()> {
-        let field = Field::new("field", DataType::Int64, true);
-        let input_primitive_1: ArrayRef =
-            Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-                Some(-1),
-                Some(-1),
-                Some(2),
-                None,
-                None,
-            ]));
-        let input_struct_1 = StructArray::from(vec![(field.clone(), input_primitive_1)]);
+    fn test_concat_dictionaries_merge() -> Result<()> {
+        let large: DictionaryArray<Int16Type> =
+            vec!["a", "b", "c", "d", "b"].into_iter().collect();
+        let small = DictionaryArray::<Int16Type>::try_new(
+            &Int16Array::from(vec![Some(0), None, Some(1), Some(2), Some(0)]),
+            &StringArray::from(vec![Some("e"), Some("b"), None]),
+        )?;
 
-        let input_primitive_2: ArrayRef =
-            Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-                Some(101),
-                Some(102),
-                Some(103),
-                None,
-            ]));
-        let input_struct_2 = StructArray::from(vec![(field, input_primitive_2)]);
+        let decode = |array: &ArrayRef| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<Int16Type>>()
+                .unwrap();
+            let values = array
+                .values()
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            array
+                .keys()
+                .iter()
+                .map(|key| key.map(|key| values.value(key as usize).to_string()))
+                .collect::<Vec<_>>()
+        };
 
-        let arr = concat(&[
-            input_struct_1.slice(1, 3).as_ref(),
-            input_struct_2.slice(1, 2).as_ref(),
-        ])?;
+        // the largest dictionary is the base of the merge wherever it is
+        let large_first = concat_dictionaries_merge::<Int16Type>(&[&large, &small])?;
+        let large_last = concat_dictionaries_merge::<Int16Type>(&[&small, &large])?;
+        let merged_values = StringArray::from(vec!["a", "b", "c", "d", "e"]);
+        for array in [&large_first, &large_last] {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<Int16Type>>()
+                .unwrap();
+            assert_eq!(array.values().as_ref(), &merged_values as &dyn Array);
+        }
 
-        let expected_primitive_output = Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-            Some(-1),
-            Some(2),
-            None,
-            Some(102),
-            Some(103),
-        ])) as ArrayRef;
+        let expected_large = vec!["a", "b", "c", "d", "b"]
+            .into_iter()
+            .map(|v| Some(v.to_string()))
+            .collect::<Vec<_>>();
+        let expected_small = vec![Some("e"), None, Some("b"), None, Some("e")]
+            .into_iter()
+            .map(|v| v.map(|v| v.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            decode(&large_first),
+            [expected_large.clone(), expected_small.clone()].concat()
+        );
+        assert_eq!(
+            decode(&large_last),
+            [expected_small, expected_large].concat()
+        );
 
-        let actual_primitive = arr
-            .as_any()
-            .downcast_ref::<StructArray>()
-            .unwrap()
-            .column(0);
-        assert_eq!(actual_primitive, &expected_primitive_output);
+        let other: DictionaryArray<Int8Type> = vec!["a"].into_iter().collect();
+        let error = concat_dictionaries_merge::<Int8Type>(&[&large, &other]).unwrap_err();
+        assert!(
+            error.to_string().contains(
+                "It is not possible to concatenate dictionary arrays with different key types"
+            ),
+            "{}",
+            error
+        );
 
+        let values = (0..200).map(|i| i.to_string()).collect::<Vec<_>>();
+        let first: DictionaryArray<Int8Type> =
+            values[..100].iter().map(|v| v.as_str()).collect();
+        let second: DictionaryArray<Int8Type> =
+            values[100..].iter().map(|v| v.as_str()).collect();
+        let error =
+            concat_dictionaries_merge::<Int8Type>(&[&first, &second]).unwrap_err();
+        assert!(matches!(error, ArrowError::DictionaryKeyOverflowError));
         Ok(())
     }
 
     #[test]
-    fn test_string_array_slices() -> Result<()> {
-        let input_1 = StringArray::from(vec!["hello", "A", "B", "C"]);
-        let input_2 = StringArray::from(vec!["world", "D", "E", "Z"]);
+    fn test_concat_lists_unify_offsets() -> Result<()> {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![Some(2), Some(3), Some(4)]),
+        ]);
+        let list = list.slice(1, 2);
+        let large_list = LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![]),
+            Some(vec![Some(5)]),
+        ]);
 
-        let arr = concat(&[input_1.slice(1, 3).as_ref(), input_2.slice(1, 2).as_ref()])?;
+        let array = concat_lists_unify_offsets(&[list.as_ref(), &large_list])?;
+        let expected = LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            None,
+            Some(vec![Some(2), Some(3), Some(4)]),
+            Some(vec![]),
+            Some(vec![Some(5)]),
+        ]);
+        assert_eq!(array.as_ref(), &expected as &dyn Array);
 
-        let expected_output = StringArray::from(vec!["A", "B", "C", "D", "E"]);
+        // lists that all fit in i32 offsets stay lists
+        let array = concat_lists_unify_offsets(&[list.as_ref(), list.as_ref()])?;
+        assert!(matches!(array.data_type(), DataType::List(_)));
+        assert_eq!(array.len(), 4);
 
-        let actual_output = arr.as_any().downcast_ref::<StringArray>().unwrap();
-        assert_eq!(actual_output, &expected_output);
+        let other =
+            LargeListArray::from_iter_primitive::<Int64Type, _, _>(vec![Some(vec![
+                Some(1),
+            ])]);
+        let error = concat_lists_unify_offsets(&[list.as_ref(), &other]).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("requires list arrays with the same item field"),
+            "{}",
+            error
+        );
 
+        let error =
+            concat_lists_unify_offsets(&[list.as_ref(), &Int32Array::from(vec![1])])
+                .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: concat_lists_unify_offsets requires list arrays, got Int32"
+        );
         Ok(())
     }
 
     #[test]
-    fn test_string_array_with_null_slices() -> Result<()> {
-        let input_1 = StringArray::from(vec![Some("hello"), None, Some("A"), Some("C")]);
-        let input_2 = StringArray::from(vec![None, Some("world"), Some("D"), None]);
+    #[cfg(feature = "force_validate")]
+    fn test_concat_force_validate() -> Result<()> {
+        // sliced nested inputs, whose child offsets do not start at zero
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![Some(2), Some(3)]),
+        ]);
+        let list = list.slice(1, 2);
+        let struct_array = StructArray::from(vec![(
+            Field::new("list", list.data_type().clone(), true),
+            list.clone(),
+        )]);
+        let sliced = struct_array.slice(1, 1);
 
-        let arr = concat(&[input_1.slice(1, 3).as_ref(), input_2.slice(1, 2).as_ref()])?;
+        // the result is validated when built
+        let array = concat(&[&struct_array, sliced.as_ref(), &struct_array])?;
+        array.data().validate_full()?;
+        assert_eq!(array.len(), 5);
+        Ok(())
+    }
 
-        let expected_output =
-            StringArray::from(vec![None, Some("A"), Some("C"), Some("world"), Some("D")]);
+    #[test]
+    #[cfg(feature = "force_validate")]
+    #[should_panic(expected = "is larger than values length")]
+    fn test_concat_force_validate_corrupt() {
+        // offsets past the end of the values
+        let data = unsafe {
+            ArrayData::builder(DataType::Utf8)
+                .len(2)
+                .add_buffer(Buffer::from_slice_ref(&[0, 1, 5]))
+                .add_buffer(Buffer::from_slice_ref(b"ab"))
+                .build_unchecked()
+        };
+        let corrupt = StringArray::from(data);
+        concat(&[&corrupt, &StringArray::from(vec!["c"])]).unwrap();
+    }
 
-        let actual_output = arr.as_any().downcast_ref::<StringArray>().unwrap();
-        assert_eq!(actual_output, &expected_output);
+    #[test]
+    fn test_concat_sorted() -> Result<()> {
+        let array = concat_sorted(
+            &[
+                &Int32Array::from(vec![1, 3, 5]),
+                &Int32Array::from(vec![2, 4, 6]),
+            ],
+            SortOptions::default(),
+        )?;
+        assert_eq!(
+            array.as_ref(),
+            &Int32Array::from(vec![1, 2, 3, 4, 5, 6]) as &dyn Array
+        );
+
+        // runs from the same input, empty inputs and nulls last
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let array = concat_sorted(
+            &[
+                &StringArray::from(vec![Some("a"), Some("b"), Some("c"), None]),
+                &StringArray::from(Vec::<&str>::new()),
+                &StringArray::from(vec![Some("b"), Some("d"), None]),
+            ],
+            options,
+        )?;
+        assert_eq!(
+            array.as_ref(),
+            &StringArray::from(vec![
+                Some("a"),
+                Some("b"),
+                Some("b"),
+                Some("c"),
+                Some("d"),
+                None,
+                None
+            ]) as &dyn Array
+        );
+
+        // descending, nulls first
+        let options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let sliced = Int64Array::from(vec![Some(100), None, Some(7), Some(1)]);
+        let sliced = sliced.slice(1, 3);
+        let array = concat_sorted(
+            &[&Int64Array::from(vec![Some(8), Some(2)]), sliced.as_ref()],
+            options,
+        )?;
+        assert_eq!(
+            array.as_ref(),
+            &Int64Array::from(vec![None, Some(8), Some(7), Some(2), Some(1)])
+                as &dyn Array
+        );
 
+        let error = concat_sorted(
+            &[&Int32Array::from(vec![1]), &Int64Array::from(vec![2])],
+            SortOptions::default(),
+        )
+        .unwrap_err();
+        assert!(
+            error.to_string().contains(
+                "It is not possible to concatenate arrays of different data types"
+            ),
+            "{}",
+            error
+        );
         Ok(())
     }
 
-    fn collect_string_dictionary(
-        dictionary: &DictionaryArray<Int32Type>,
-    ) -> Vec<Option<String>> {
-        let values = dictionary.values();
-        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
-
-        dictionary
-            .keys()
-            .iter()
-            .map(|key| key.map(|key| values.value(key as _).to_string()))
-            .collect()
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "arrays[1] is not sorted")]
+    fn test_concat_sorted_unsorted() {
+        concat_sorted(
+            &[&Int32Array::from(vec![1, 2]), &Int32Array::from(vec![3, 0])],
+            SortOptions::default(),
+        )
+        .unwrap();
     }
 
-    fn concat_dictionary(
-        input_1: DictionaryArray<Int32Type>,
-        input_2: DictionaryArray<Int32Type>,
-    ) -> Vec<Option<String>> {
-        let concat = concat(&[&input_1 as _, &input_2 as _]).unwrap();
-        let concat = concat
+    #[test]
+    fn test_concat_dictionaries_with_mapping() -> Result<()> {
+        let first = DictionaryArray::<Int32Type>::try_new(
+            &Int32Array::from(vec![Some(0), Some(2), None, Some(1)]),
+            &StringArray::from(vec![Some("x"), None, Some("y")]),
+        )?;
+        let second = DictionaryArray::<Int32Type>::try_new(
+            &Int32Array::from(vec![3, 0, 2, 1, 2]),
+            &StringArray::from(vec!["z", "y", "v", "w"]),
+        )?;
+
+        let (array, mapping) =
+            concat_dictionaries_with_mapping::<Int32Type>(&[&first, &second])?;
+        // the largest dictionary, of `second`, is the base of the merge
+        assert_eq!(
+            mapping,
+            vec![
+                vec![Some(4), None, Some(1)],
+                vec![Some(0), Some(1), Some(2), Some(3)]
+            ]
+        );
+
+        let array = array
             .as_any()
             .downcast_ref::<DictionaryArray<Int32Type>>()
             .unwrap();
-
-        collect_string_dictionary(concat)
+        assert_eq!(
+            array.values().as_ref(),
+            &StringArray::from(vec!["z", "y", "v", "w", "x"]) as &dyn Array
+        );
+        assert_eq!(
+            array.keys(),
+            &Int32Array::from(vec![
+                Some(4),
+                Some(1),
+                None,
+                None,
+                Some(3),
+                Some(0),
+                Some(2),
+                Some(1),
+                Some(2)
+            ])
+        );
+        Ok(())
     }
 
     #[test]
-    fn test_string_dictionary_array() {
-        let input_1: DictionaryArray<Int32Type> =
-            vec!["hello", "A", "B", "hello", "hello", "C"]
-                .into_iter()
-                .collect();
-        let input_2: DictionaryArray<Int32Type> =
-            vec!["hello", "E", "E", "hello", "F", "E"]
-                .into_iter()
-                .collect();
-
-        let expected: Vec<_> = vec![
-            "hello", "A", "B", "hello", "hello", "C", "hello", "E", "E", "hello", "F",
-            "E",
-        ]
-        .into_iter()
-        .map(|x| Some(x.to_string()))
-        .collect();
+    fn test_concat_grouped_by_type() -> Result<()> {
+        let results = concat_grouped_by_type(&[
+            &Int64Array::from(vec![Some(1), None]),
+            &StringArray::from(vec!["a", "b"]),
+            &Int64Array::from(vec![3]),
+            &StringArray::from(vec![Some("c"), None]),
+            &Int64Array::from(vec![4, 5]),
+        ])?;
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0].as_ref(),
+            &Int64Array::from(vec![Some(1), None, Some(3), Some(4), Some(5)])
+                as &dyn Array
+        );
+        assert_eq!(
+            results[1].as_ref(),
+            &StringArray::from(vec![Some("a"), Some("b"), Some("c"), None]) as &dyn Array
+        );
 
-        let concat = concat_dictionary(input_1, input_2);
-        assert_eq!(concat, expected);
+        assert!(concat_grouped_by_type(&[])?.is_empty());
+        Ok(())
     }
 
     #[test]
-    fn test_string_dictionary_array_nulls() {
-        let input_1: DictionaryArray<Int32Type> =
-            vec![Some("foo"), Some("bar"), None, Some("fiz")]
-                .into_iter()
-                .collect();
-        let input_2: DictionaryArray<Int32Type> = vec![None].into_iter().collect();
-        let expected = vec![
-            Some("foo".to_string()),
-            Some("bar".to_string()),
-            None,
-            Some("fiz".to_string()),
-            None,
-        ];
+    fn test_concat_with_callback() -> Result<()> {
+        let input_1 = StringArray::from(vec![Some("a"), None]);
+        let input_2 = StringArray::from(Vec::<&str>::new());
+        let input_3 = StringArray::from(vec!["b", "c", "d"]);
+        let inputs: [&dyn Array; 3] = [&input_1, &input_2, &input_3];
 
-        let concat = concat_dictionary(input_1, input_2);
-        assert_eq!(concat, expected);
-    }
+        let mut source_ids = vec![];
+        let mut calls = vec![];
+        let array = concat_with_callback(&inputs, |i, start, len| {
+            assert_eq!(start, source_ids.len());
+            source_ids.extend(std::iter::repeat(i as i32).take(len));
+            calls.push((i, start, len));
+        })?;
+        // the empty input is skipped
+        assert_eq!(calls, vec![(0, 0, 2), (2, 2, 3)]);
 
-    #[test]
-    fn test_concat_string_sizes() -> Result<()> {
-        let a: LargeStringArray = ((0..150).map(|_| Some("foo"))).collect();
-        let b: LargeStringArray = ((0..150).map(|_| Some("foo"))).collect();
-        let c = LargeStringArray::from(vec![Some("foo"), Some("bar"), None, Some("baz")]);
-        // 150 * 3 = 450
-        // 150 * 3 = 450
-        // 3 * 3   = 9
-        // ------------+
-        // 909
-        // closest 64 byte aligned cap = 960
+        let (expected, expected_source_ids) = concat_with_source_ids(&inputs)?;
+        assert_eq!(array.as_ref(), expected.as_ref());
+        assert_eq!(Int32Array::from(source_ids), expected_source_ids);
 
-        let arr = concat(&[&a, &b, &c])?;
-        // this would have been 1280 if we did not precompute the value lengths.
-        assert_eq!(arr.data().buffers()[1].capacity(), 960);
+        let mut calls = 0;
+        let result =
+            concat_with_callback(&[&input_1, &Int32Array::from(vec![1])], |_, _, _| {
+                calls += 1
+            });
+        assert!(result.is_err());
+        assert_eq!(calls, 0);
 
+        // a single input and null arrays are copied like the others
+        let nulls = NullArray::new(2);
+        let mut calls = vec![];
+        let array = concat_with_callback(&[&input_3, &nulls], |i, start, len| {
+            calls.push((i, start, len))
+        })?;
+        assert_eq!(array.as_ref(), concat(&[&input_3, &nulls])?.as_ref());
+        assert_eq!(calls, vec![(0, 0, 3), (1, 3, 2)]);
+        let mut calls = vec![];
+        let array = concat_with_callback(&[&input_1], |i, start, len| {
+            calls.push((i, start, len))
+        })?;
+        assert_eq!(array.as_ref(), &input_1 as &dyn Array);
+        assert_eq!(calls, vec![(0, 0, 2)]);
         Ok(())
     }
 
     #[test]
-    fn test_dictionary_concat_reuse() {
-        let array: DictionaryArray<Int8Type> =
-            vec!["a", "a", "b", "c"].into_iter().collect();
-        let copy: DictionaryArray<Int8Type> = array.data().clone().into();
-
-        // dictionary is "a", "b", "c"
+    fn test_concat_with_float_stats() -> Result<()> {
+        let input_1 = Float32Array::from(vec![Some(1.0), None, Some(-0.0)]);
+        let input_2 = Float32Array::from(vec![f32::INFINITY, 2.5]);
+        let (array, has_nan) = concat_with_float_stats(&[&input_1, &input_2])?;
+        assert!(!has_nan);
         assert_eq!(
-            array.values(),
-            &(Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef)
+            array.as_ref(),
+            &Float32Array::from(vec![
+                Some(1.0),
+                None,
+                Some(-0.0),
+                Some(f32::INFINITY),
+                Some(2.5)
+            ]) as &dyn Array
         );
-        assert_eq!(array.keys(), &Int8Array::from(vec![0, 0, 1, 2]));
 
-        // concatenate it with itself
-        let combined = concat(&[&copy as _, &array as _]).unwrap();
+        let input_3 = Float32Array::from(vec![Some(3.0), Some(f32::NAN)]);
+        let (array, has_nan) = concat_with_float_stats(&[&input_1, &input_3])?;
+        assert!(has_nan);
+        assert_eq!(array.len(), 5);
 
-        let combined = combined
-            .as_any()
-            .downcast_ref::<DictionaryArray<Int8Type>>()
-            .unwrap();
+        // NaN values of null slots are ignored
+        let data = ArrayData::builder(DataType::Float32)
+            .len(2)
+            .add_buffer(input_3.data().buffers()[0].clone())
+            .null_bit_buffer(Some(Buffer::from([0b01])))
+            .build()?;
+        let masked = Float32Array::from(data);
+        let (_, has_nan) = concat_with_float_stats(&[&input_2, &masked])?;
+        assert!(!has_nan);
+
+        let sliced = input_3.slice(1, 1);
+        let sliced = sliced.as_any().downcast_ref::<Float32Array>().unwrap();
+        let (array, has_nan) = concat_with_float_stats(&[&masked, sliced])?;
+        assert!(has_nan);
+        assert_eq!(array.as_ref(), concat(&[&masked, sliced])?.as_ref());
+
+        let sliced = masked.slice(1, 1);
+        let sliced = sliced.as_any().downcast_ref::<Float32Array>().unwrap();
+        let (array, has_nan) = concat_with_float_stats(&[&input_1, sliced])?;
+        assert!(!has_nan);
+        assert_eq!(array.as_ref(), concat(&[&input_1, sliced])?.as_ref());
+
+        assert!(concat_with_float_stats::<Float32Type>(&[]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_with_scalar() -> Result<()> {
+        let array = Int64Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let sliced = array.slice(1, 2);
 
+        let result = concat_with_scalar::<Int64Type>(sliced.as_ref(), Some(5))?;
         assert_eq!(
-            combined.values(),
-            &(Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef),
-            "Actual: {:#?}",
-            combined
+            result.as_ref(),
+            &Int64Array::from(vec![None, Some(3), Some(5)]) as &dyn Array
         );
-
+        let result = concat_with_scalar::<Int64Type>(sliced.as_ref(), None)?;
         assert_eq!(
-            combined.keys(),
-            &Int8Array::from(vec![0, 0, 1, 2, 0, 0, 1, 2])
+            result.as_ref(),
+            &Int64Array::from(vec![None, Some(3), None]) as &dyn Array
         );
 
-        // Should have reused the dictionary
-        assert!(array.data().child_data()[0].ptr_eq(&combined.data().child_data()[0]));
-        assert!(copy.data().child_data()[0].ptr_eq(&combined.data().child_data()[0]));
+        // same as concat with a single row array
+        let no_nulls = Int64Array::from(vec![7, 8]);
+        for scalar in [Some(9), None] {
+            let result = concat_with_scalar::<Int64Type>(&no_nulls, scalar)?;
+            let expected = concat(&[&no_nulls, &Int64Array::from(vec![scalar])])?;
+            assert_eq!(result.as_ref(), expected.as_ref());
+            assert_eq!(result.null_count(), expected.null_count());
+        }
 
-        let new: DictionaryArray<Int8Type> = vec!["d"].into_iter().collect();
-        let combined = concat(&[&copy as _, &array as _, &new as _]).unwrap();
+        // the data type of the array is kept
+        let timestamps =
+            TimestampSecondArray::from_opt_vec(vec![Some(1)], Some("+00:00".to_string()));
+        let result = concat_with_scalar::<TimestampSecondType>(&timestamps, Some(2))?;
+        assert_eq!(result.data_type(), timestamps.data_type());
 
-        // Should not have reused the dictionary
-        assert!(!array.data().child_data()[0].ptr_eq(&combined.data().child_data()[0]));
-        assert!(!copy.data().child_data()[0].ptr_eq(&combined.data().child_data()[0]));
-        assert!(!new.data().child_data()[0].ptr_eq(&combined.data().child_data()[0]));
+        let error = concat_with_scalar::<Int32Type>(&array, Some(5)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: concat_with_scalar expects an array of type Int32, got Int64"
+        );
+        Ok(())
     }
 }
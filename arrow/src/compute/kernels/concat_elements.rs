@@ -17,7 +17,10 @@
 
 use crate::array::*;
 use crate::compute::util::combine_option_bitmap;
+use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
+use crate::util::bit_util;
+use std::sync::Arc;
 
 /// Returns the elementwise concatenation of a [`StringArray`].
 ///
@@ -45,38 +48,354 @@ pub fn concat_elements_utf8<Offset: OffsetSizeTrait>(
         )));
     }
 
-    let output_bitmap = combine_option_bitmap(&[left.data(), right.data()], left.len())?;
+    concat_elements_bytes::<Offset>(&[left.data(), right.data()], &[], false)
+        .map(Into::into)
+}
+
+/// Returns the elementwise concatenation of a [`BinaryArray`].
+///
+/// An index of the resulting [`BinaryArray`] is null if any of
+/// `BinaryArray` are null at that location.
+///
+/// ```text
+/// e.g:
+///
+///   [[0x01]] + [[0x02, 0x03]] = [[0x01, 0x02, 0x03]]
+///
+///   [[0x01], [0x02]] + [None, [0x03]] = [None, [0x02, 0x03]]
+/// ```
+///
+/// An error will be returned if `left` and `right` have different lengths
+pub fn concat_elements_binary<Offset: OffsetSizeTrait>(
+    left: &GenericBinaryArray<Offset>,
+    right: &GenericBinaryArray<Offset>,
+) -> Result<GenericBinaryArray<Offset>> {
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(format!(
+            "Arrays must have the same length: {} != {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    concat_elements_bytes::<Offset>(&[left.data(), right.data()], &[], false)
+        .map(Into::into)
+}
+
+/// Returns the elementwise concatenation of two arrays of the same string or
+/// binary type, dispatching to [`concat_elements_utf8`] or
+/// [`concat_elements_binary`] based on their [`DataType`].
+///
+/// An error will be returned if `left` and `right` have different lengths or
+/// data types, or if their data type is not one of `Utf8`, `LargeUtf8`,
+/// `Binary` and `LargeBinary`
+pub fn concat_elements_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    if left.data_type() != right.data_type() {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot concat elements of arrays of different types: {} != {}",
+            left.data_type(),
+            right.data_type()
+        )));
+    }
+
+    match left.data_type() {
+        DataType::Utf8 => {
+            let left = left.as_any().downcast_ref::<StringArray>().unwrap();
+            let right = right.as_any().downcast_ref::<StringArray>().unwrap();
+            Ok(Arc::new(concat_elements_utf8(left, right)?))
+        }
+        DataType::LargeUtf8 => {
+            let left = left.as_any().downcast_ref::<LargeStringArray>().unwrap();
+            let right = right.as_any().downcast_ref::<LargeStringArray>().unwrap();
+            Ok(Arc::new(concat_elements_utf8(left, right)?))
+        }
+        DataType::Binary => {
+            let left = left.as_any().downcast_ref::<BinaryArray>().unwrap();
+            let right = right.as_any().downcast_ref::<BinaryArray>().unwrap();
+            Ok(Arc::new(concat_elements_binary(left, right)?))
+        }
+        DataType::LargeBinary => {
+            let left = left.as_any().downcast_ref::<LargeBinaryArray>().unwrap();
+            let right = right.as_any().downcast_ref::<LargeBinaryArray>().unwrap();
+            Ok(Arc::new(concat_elements_binary(left, right)?))
+        }
+        data_type => Err(ArrowError::ComputeError(format!(
+            "Cannot concat elements of arrays of type {}",
+            data_type
+        ))),
+    }
+}
+
+/// Returns the elementwise concatenation of many [`StringArray`]s.
+///
+/// An index of the resulting [`StringArray`] is null if any of the
+/// input arrays are null at that location.
+///
+/// ```text
+/// e.g:
+///
+///   ["a", "b"] + [None, "c"] + ["d", "e"] = [None, "bce"]
+/// ```
+///
+/// An error will be returned if `arrays` is empty or if the arrays
+/// have different lengths
+pub fn concat_elements_utf8_many<Offset: OffsetSizeTrait>(
+    arrays: &[&GenericStringArray<Offset>],
+) -> Result<GenericStringArray<Offset>> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let size = arrays[0].len();
+    if !arrays.iter().all(|array| array.len() == size) {
+        return Err(ArrowError::ComputeError(format!(
+            "Arrays must have the same length of {}",
+            size,
+        )));
+    }
 
-    let left_offsets = left.value_offsets();
-    let right_offsets = right.value_offsets();
+    let data = arrays.iter().map(|array| array.data()).collect::<Vec<_>>();
+    concat_elements_bytes::<Offset>(&data, &[], false).map(Into::into)
+}
+
+/// Returns the elementwise concatenation of a [`StringArray`], with
+/// `separator` inserted between the values of `left` and `right`.
+///
+/// An index of the resulting [`StringArray`] is null if any of
+/// `StringArray` are null at that location, in which case no separator is
+/// emitted for it either.
+///
+/// ```text
+/// e.g:
+///
+///   ["Hello"] + ["World"] with ", " = ["Hello, World"]
+///
+///   ["a", "b"] + [None, "c"] with "," = [None, "b,c"]
+/// ```
+///
+/// An error will be returned if `left` and `right` have different lengths
+pub fn concat_elements_utf8_with_separator<Offset: OffsetSizeTrait>(
+    left: &GenericStringArray<Offset>,
+    right: &GenericStringArray<Offset>,
+    separator: &str,
+) -> Result<GenericStringArray<Offset>> {
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(format!(
+            "Arrays must have the same length: {} != {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    concat_elements_bytes::<Offset>(
+        &[left.data(), right.data()],
+        separator.as_bytes(),
+        false,
+    )
+    .map(Into::into)
+}
+
+/// Returns the elementwise concatenation of a [`StringArray`], treating nulls
+/// as empty strings.
+///
+/// An index of the resulting [`StringArray`] is null only if both `left` and
+/// `right` are null at that location, like SQL's `CONCAT`.
+///
+/// ```text
+/// e.g:
+///
+///   ["a", None, None] + [None, "b", None] = ["a", "b", None]
+/// ```
+///
+/// An error will be returned if `left` and `right` have different lengths
+pub fn concat_elements_utf8_coalesce<Offset: OffsetSizeTrait>(
+    left: &GenericStringArray<Offset>,
+    right: &GenericStringArray<Offset>,
+) -> Result<GenericStringArray<Offset>> {
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(format!(
+            "Arrays must have the same length: {} != {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    concat_elements_bytes::<Offset>(&[left.data(), right.data()], &[], true)
+        .map(Into::into)
+}
+
+/// Returns a [`StringArray`] with each value of `values` repeated the number of
+/// times given by the same index of `counts`, like SQL's `REPEAT`.
+///
+/// An index of the resulting [`StringArray`] is null if either `values` or
+/// `counts` is null at that location. A count of zero or less results in an
+/// empty string.
+///
+/// ```text
+/// e.g:
+///
+///   repeat(["ab", "c", None, "d"], [2, 0, 1, None]) = ["abab", "", None, None]
+/// ```
+///
+/// An error will be returned if `values` and `counts` have different lengths,
+/// or if the repeated values do not fit in the offsets of the output.
+pub fn repeat_utf8<Offset: OffsetSizeTrait>(
+    values: &GenericStringArray<Offset>,
+    counts: &Int32Array,
+) -> Result<GenericStringArray<Offset>> {
+    if values.len() != counts.len() {
+        return Err(ArrowError::ComputeError(format!(
+            "Arrays must have the same length: {} != {}",
+            values.len(),
+            counts.len()
+        )));
+    }
 
-    let left_buffer = left.value_data();
-    let right_buffer = right.value_data();
-    let left_values = left_buffer.as_slice();
-    let right_values = right_buffer.as_slice();
+    let len = values.len();
+    let output_bitmap = combine_option_bitmap(&[values.data(), counts.data()], len)?;
+    let is_valid = |idx: usize| {
+        output_bitmap
+            .as_ref()
+            .map(|bitmap| bit_util::get_bit(bitmap.as_slice(), idx))
+            .unwrap_or(true)
+    };
+    let count = |idx: usize| counts.value(idx).max(0) as usize;
 
-    let mut output_values = BufferBuilder::<u8>::new(
-        left_values.len() + right_values.len()
-            - left_offsets[0].to_usize().unwrap()
-            - right_offsets[0].to_usize().unwrap(),
-    );
+    let values_size = (0..len)
+        .filter(|idx| is_valid(*idx))
+        .map(|idx| values.value_length(idx).to_usize().unwrap() * count(idx))
+        .sum::<usize>();
+    if Offset::from_usize(values_size).is_none() {
+        return Err(ArrowError::ComputeError(format!(
+            "repeat would produce {} value bytes, which overflows the offsets of {:?}",
+            values_size,
+            values.data_type()
+        )));
+    }
 
-    let mut output_offsets = BufferBuilder::<Offset>::new(left_offsets.len());
+    let mut output_values = BufferBuilder::<u8>::new(values_size);
+    let mut output_offsets = BufferBuilder::<Offset>::new(len + 1);
     output_offsets.append(Offset::zero());
-    for (left_idx, right_idx) in left_offsets.windows(2).zip(right_offsets.windows(2)) {
-        output_values.append_slice(
-            &left_values
-                [left_idx[0].to_usize().unwrap()..left_idx[1].to_usize().unwrap()],
-        );
-        output_values.append_slice(
-            &right_values
-                [right_idx[0].to_usize().unwrap()..right_idx[1].to_usize().unwrap()],
-        );
+    for idx in 0..len {
+        if is_valid(idx) {
+            let value = values.value(idx).as_bytes();
+            (0..count(idx)).for_each(|_| output_values.append_slice(value));
+        }
         output_offsets.append(Offset::from_usize(output_values.len()).unwrap());
     }
 
-    let builder = ArrayDataBuilder::new(GenericStringArray::<Offset>::get_data_type())
-        .len(left.len())
+    let builder = ArrayDataBuilder::new(values.data_type().clone())
+        .len(len)
+        .add_buffer(output_offsets.finish())
+        .add_buffer(output_values.finish())
+        .null_bit_buffer(output_bitmap);
+
+    // SAFETY - offsets valid by construction
+    Ok(unsafe { builder.build_unchecked() }.into())
+}
+
+/// Returns the elementwise concatenation of a substring of each value of
+/// `left` with the value of `right` at the same index, without materializing
+/// the substrings.
+///
+/// The substrings are taken like [`substring_by_char`]: `start` and `length`
+/// are counted in characters, a negative `start` counts from the end of the
+/// value, and a `length` of `None` takes the rest of the value. Out of range
+/// `start` and `length` are clamped to the value, so they result in an empty
+/// or shorter substring rather than an error.
+///
+/// An index of the resulting [`StringArray`] is null if either `left` or
+/// `right` is null at that location.
+///
+/// ```text
+/// e.g:
+///
+///   substring_concat(["hello", "ab", None], 0, Some(3), ["!", "?", "x"]) = ["hel!", "ab?", None]
+/// ```
+///
+/// An error will be returned if `left` and `right` have different lengths, or
+/// if the output values do not fit in its offsets.
+///
+/// [`substring_by_char`]: crate::compute::kernels::substring::substring_by_char
+pub fn substring_concat<Offset: OffsetSizeTrait>(
+    left: &GenericStringArray<Offset>,
+    start: i64,
+    length: Option<u64>,
+    right: &GenericStringArray<Offset>,
+) -> Result<GenericStringArray<Offset>> {
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(format!(
+            "Arrays must have the same length: {} != {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    let len = left.len();
+    let output_bitmap = combine_option_bitmap(&[left.data(), right.data()], len)?;
+    let is_valid = |idx: usize| {
+        output_bitmap
+            .as_ref()
+            .map(|bitmap| bit_util::get_bit(bitmap.as_slice(), idx))
+            .unwrap_or(true)
+    };
+
+    // exact for single byte characters
+    let values_size = (0..len)
+        .filter(|idx| is_valid(*idx))
+        .map(|idx| {
+            let left_len = left.value_length(idx).to_usize().unwrap();
+            let left_len =
+                length.map_or(left_len, |length| left_len.min(length as usize));
+            left_len + right.value_length(idx).to_usize().unwrap()
+        })
+        .sum::<usize>();
+
+    let mut output_values = BufferBuilder::<u8>::new(values_size);
+    let mut output_offsets = BufferBuilder::<Offset>::new(len + 1);
+    output_offsets.append(Offset::zero());
+    for idx in 0..len {
+        if is_valid(idx) {
+            let value = left.value(idx);
+            let char_count = value.chars().count();
+            let start = match start >= 0 {
+                true => (start as usize).min(char_count),
+                false => char_count - (start.unsigned_abs() as usize).min(char_count),
+            };
+            let length = length.map_or(char_count - start, |length| {
+                (length as usize).min(char_count - start)
+            });
+            // the byte offset of the character at `index`
+            let byte_offset = |index: usize| {
+                value
+                    .char_indices()
+                    .nth(index)
+                    .map_or(value.len(), |(offset, _)| offset)
+            };
+            let begin = byte_offset(start);
+            let end = begin
+                + value[begin..]
+                    .chars()
+                    .take(length)
+                    .map(char::len_utf8)
+                    .sum::<usize>();
+
+            output_values.append_slice(&value.as_bytes()[begin..end]);
+            output_values.append_slice(right.value(idx).as_bytes());
+        }
+        let offset = Offset::from_usize(output_values.len()).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "substring_concat would produce more value bytes than the offsets of {:?} can hold",
+                left.data_type()
+            ))
+        })?;
+        output_offsets.append(offset);
+    }
+
+    let builder = ArrayDataBuilder::new(left.data_type().clone())
+        .len(len)
         .add_buffer(output_offsets.finish())
         .add_buffer(output_values.finish())
         .null_bit_buffer(output_bitmap);
@@ -85,9 +404,257 @@ pub fn concat_elements_utf8<Offset: OffsetSizeTrait>(
     Ok(unsafe { builder.build_unchecked() }.into())
 }
 
+/// Returns a [`StringArray`] with each value of `values` left-padded with `pad`
+/// to the number of characters given by the same index of `lengths`, like
+/// SQL's `LPAD`.
+///
+/// `pad` is repeated as many times as needed, and its last repetition cut
+/// short, to reach the length. Values that are longer than the length are
+/// truncated to their first `length` characters, and a length of zero or less
+/// results in an empty string. Values are left as is, apart from being
+/// truncated, if `pad` is empty. Lengths are counted in characters, not bytes.
+///
+/// An index of the resulting [`StringArray`] is null if either `values` or
+/// `lengths` is null at that location.
+///
+/// ```text
+/// e.g:
+///
+///   lpad(["ab", "abcd", None, "a"], [4, 2, 3, None], "xy") = ["xyab", "ab", None, None]
+/// ```
+///
+/// An error will be returned if `values` and `lengths` have different lengths,
+/// or if the padded values do not fit in the offsets of the output.
+pub fn lpad_utf8<Offset: OffsetSizeTrait>(
+    values: &GenericStringArray<Offset>,
+    lengths: &Int32Array,
+    pad: &str,
+) -> Result<GenericStringArray<Offset>> {
+    pad_utf8(values, lengths, pad, true)
+}
+
+/// Returns a [`StringArray`] with each value of `values` left-padded with `pad`
+/// to `length` characters.
+///
+/// This behaves like [`lpad_utf8`] with the same length for every value.
+pub fn lpad_utf8_scalar<Offset: OffsetSizeTrait>(
+    values: &GenericStringArray<Offset>,
+    length: i32,
+    pad: &str,
+) -> Result<GenericStringArray<Offset>> {
+    let lengths = Int32Array::from(vec![length; values.len()]);
+    pad_utf8(values, &lengths, pad, true)
+}
+
+/// Returns a [`StringArray`] with each value of `values` right-padded with
+/// `pad` to the number of characters given by the same index of `lengths`,
+/// like SQL's `RPAD`.
+///
+/// Padding, truncation and nulls are handled like in [`lpad_utf8`]: values
+/// longer than the length are truncated to their first characters.
+///
+/// ```text
+/// e.g:
+///
+///   rpad(["ab", "abcd", None, "a"], [5, 2, 3, None], "xy") = ["abxyx", "ab", None, None]
+/// ```
+///
+/// An error will be returned if `values` and `lengths` have different lengths,
+/// or if the padded values do not fit in the offsets of the output.
+pub fn rpad_utf8<Offset: OffsetSizeTrait>(
+    values: &GenericStringArray<Offset>,
+    lengths: &Int32Array,
+    pad: &str,
+) -> Result<GenericStringArray<Offset>> {
+    pad_utf8(values, lengths, pad, false)
+}
+
+/// Returns a [`StringArray`] with each value of `values` right-padded with
+/// `pad` to `length` characters.
+///
+/// This behaves like [`rpad_utf8`] with the same length for every value.
+pub fn rpad_utf8_scalar<Offset: OffsetSizeTrait>(
+    values: &GenericStringArray<Offset>,
+    length: i32,
+    pad: &str,
+) -> Result<GenericStringArray<Offset>> {
+    let lengths = Int32Array::from(vec![length; values.len()]);
+    pad_utf8(values, &lengths, pad, false)
+}
+
+/// Pads each value of `values` with `pad` to the number of characters given by
+/// the same index of `lengths`, on the left if `left` is `true`, truncating the
+/// longer values.
+fn pad_utf8<Offset: OffsetSizeTrait>(
+    values: &GenericStringArray<Offset>,
+    lengths: &Int32Array,
+    pad: &str,
+    left: bool,
+) -> Result<GenericStringArray<Offset>> {
+    if values.len() != lengths.len() {
+        return Err(ArrowError::ComputeError(format!(
+            "Arrays must have the same length: {} != {}",
+            values.len(),
+            lengths.len()
+        )));
+    }
+
+    let len = values.len();
+    let output_bitmap = combine_option_bitmap(&[values.data(), lengths.data()], len)?;
+    let is_valid = |idx: usize| {
+        output_bitmap
+            .as_ref()
+            .map(|bitmap| bit_util::get_bit(bitmap.as_slice(), idx))
+            .unwrap_or(true)
+    };
+    let length = |idx: usize| lengths.value(idx).max(0) as usize;
+
+    // exact for single byte characters
+    let values_size = (0..len)
+        .filter(|idx| is_valid(*idx))
+        .map(|idx| {
+            values
+                .value_length(idx)
+                .to_usize()
+                .unwrap()
+                .max(length(idx))
+        })
+        .sum::<usize>();
+
+    let mut output_values = BufferBuilder::<u8>::new(values_size);
+    let mut output_offsets = BufferBuilder::<Offset>::new(len + 1);
+    output_offsets.append(Offset::zero());
+    let append_pad = |output_values: &mut BufferBuilder<u8>, count: usize| {
+        let mut buf = [0; 4];
+        pad.chars()
+            .cycle()
+            .take(count)
+            .for_each(|c| output_values.append_slice(c.encode_utf8(&mut buf).as_bytes()));
+    };
+    for idx in 0..len {
+        if is_valid(idx) {
+            let value = values.value(idx);
+            let length = length(idx);
+            let (value, pad_count) = match value.char_indices().nth(length) {
+                Some((end, _)) => (&value[..end], 0),
+                None if pad.is_empty() => (value, 0),
+                None => (value, length - value.chars().count()),
+            };
+            if left {
+                append_pad(&mut output_values, pad_count);
+            }
+            output_values.append_slice(value.as_bytes());
+            if !left {
+                append_pad(&mut output_values, pad_count);
+            }
+        }
+        let offset = Offset::from_usize(output_values.len()).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "padding would produce more value bytes than the offsets of {:?} can hold",
+                values.data_type()
+            ))
+        })?;
+        output_offsets.append(offset);
+    }
+
+    let builder = ArrayDataBuilder::new(values.data_type().clone())
+        .len(len)
+        .add_buffer(output_offsets.finish())
+        .add_buffer(output_values.finish())
+        .null_bit_buffer(output_bitmap);
+
+    // SAFETY - offsets valid by construction
+    Ok(unsafe { builder.build_unchecked() }.into())
+}
+
+/// Concatenates the values of variable sized arrays of the same length and
+/// data type element by element, in a single pass over the inputs.
+///
+/// `separator` is inserted between the values of consecutive arrays. Null
+/// output slots are left empty.
+///
+/// If `nulls_as_empty` is `false`, an output slot is null if any of the inputs
+/// is null. Otherwise null inputs are treated as empty values, and an output
+/// slot is only null if all of the inputs are null.
+fn concat_elements_bytes<Offset: OffsetSizeTrait>(
+    arrays: &[&ArrayData],
+    separator: &[u8],
+    nulls_as_empty: bool,
+) -> Result<ArrayData> {
+    let len = arrays[0].len();
+    let output_bitmap = if !nulls_as_empty {
+        combine_option_bitmap(arrays, len)?
+    } else if arrays.iter().any(|array| array.null_count() == 0) {
+        None
+    } else {
+        let mut builder = BooleanBufferBuilder::new(len);
+        (0..len).for_each(|idx| {
+            builder.append(arrays.iter().any(|array| array.is_valid(idx)))
+        });
+        Some(builder.finish())
+    };
+
+    // the offsets are sliced from the array offset, so only take `len + 1` of them
+    let offsets = arrays
+        .iter()
+        .map(|data| &data.buffer::<Offset>(0)[..=len])
+        .collect::<Vec<_>>();
+    let values = arrays
+        .iter()
+        .map(|data| data.buffers()[1].as_slice())
+        .collect::<Vec<_>>();
+
+    let values_size: usize = offsets
+        .iter()
+        .map(|offsets| (offsets[len] - offsets[0]).to_usize().unwrap())
+        .sum();
+    let separators_size = separator.len() * (arrays.len() - 1) * len;
+    let mut output_values = BufferBuilder::<u8>::new(values_size + separators_size);
+
+    let mut output_offsets = BufferBuilder::<Offset>::new(len + 1);
+    output_offsets.append(Offset::zero());
+    for idx in 0..len {
+        let is_valid = output_bitmap
+            .as_ref()
+            .map(|bitmap| bit_util::get_bit(bitmap.as_slice(), idx))
+            .unwrap_or(true);
+        if is_valid {
+            for (i, (offsets, values)) in offsets.iter().zip(&values).enumerate() {
+                if i > 0 {
+                    output_values.append_slice(separator);
+                }
+                if nulls_as_empty && arrays[i].is_null(idx) {
+                    continue;
+                }
+                output_values.append_slice(
+                    &values[offsets[idx].to_usize().unwrap()
+                        ..offsets[idx + 1].to_usize().unwrap()],
+                );
+            }
+        }
+        let offset = Offset::from_usize(output_values.len()).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "concat would produce more value bytes than the offsets of {:?} can hold",
+                arrays[0].data_type()
+            ))
+        })?;
+        output_offsets.append(offset);
+    }
+
+    let builder = ArrayDataBuilder::new(arrays[0].data_type().clone())
+        .len(len)
+        .add_buffer(output_offsets.finish())
+        .add_buffer(output_values.finish())
+        .null_bit_buffer(output_bitmap);
+
+    // SAFETY - offsets valid by construction
+    Ok(unsafe { builder.build_unchecked() })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::buffer::{Buffer, MutableBuffer};
     #[test]
     fn test_string_concat() {
         let left = [Some("foo"), Some("bar"), None]
@@ -190,4 +757,506 @@ mod tests {
 
         assert_eq!(output, expected);
     }
+
+    #[test]
+    fn test_string_concat_sliced_nulls() {
+        let left = StringArray::from(vec![Some("a"), None, Some("b"), Some("c")]);
+        let right = StringArray::from(vec![Some("d"), Some("e"), None, Some("g")]);
+        let no_nulls = StringArray::from(vec!["w", "x", "y", "z"]);
+
+        let left_slice = left.slice(1, 2);
+        let left_slice = left_slice.as_any().downcast_ref::<StringArray>().unwrap();
+        let right_slice = right.slice(1, 2);
+        let right_slice = right_slice.as_any().downcast_ref::<StringArray>().unwrap();
+        let no_nulls_slice = no_nulls.slice(1, 2);
+        let no_nulls_slice = no_nulls_slice
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+
+        // the un-sliced equivalents
+        let left_expected = StringArray::from(vec![None, Some("b")]);
+        let right_expected = StringArray::from(vec![Some("e"), None]);
+        let no_nulls_expected = StringArray::from(vec!["x", "y"]);
+
+        let output = concat_elements_utf8(left_slice, right_slice).unwrap();
+        let expected = concat_elements_utf8(&left_expected, &right_expected).unwrap();
+        assert_eq!(output, expected);
+        assert_eq!(output, StringArray::from(vec![None::<&str>, None]));
+
+        // only one side has a null bitmap
+        let output = concat_elements_utf8(left_slice, no_nulls_slice).unwrap();
+        let expected = concat_elements_utf8(&left_expected, &no_nulls_expected).unwrap();
+        assert_eq!(output, expected);
+        assert_eq!(output, StringArray::from(vec![None, Some("by")]));
+
+        let output = concat_elements_utf8(no_nulls_slice, right_slice).unwrap();
+        let expected = concat_elements_utf8(&no_nulls_expected, &right_expected).unwrap();
+        assert_eq!(output, expected);
+        assert_eq!(output, StringArray::from(vec![Some("xe"), None]));
+    }
+
+    #[test]
+    fn test_binary_concat() {
+        let left = [Some(b"foo".as_ref()), Some(b"bar"), None]
+            .into_iter()
+            .collect::<BinaryArray>();
+        let right = [None, Some(b"yyy".as_ref()), Some(b"zzz")]
+            .into_iter()
+            .collect::<BinaryArray>();
+
+        let output = concat_elements_binary(&left, &right).unwrap();
+
+        let expected = [None, Some(b"baryyy".as_ref()), None]
+            .into_iter()
+            .collect::<BinaryArray>();
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_large_binary_concat_slice() {
+        let left = [Some(b"\x00\x01".as_ref()), Some(b"\xff"), None, Some(b"")]
+            .into_iter()
+            .collect::<LargeBinaryArray>();
+        let right = [Some(b"\x02".as_ref()), None, Some(b"\x03"), Some(b"\x04")]
+            .into_iter()
+            .collect::<LargeBinaryArray>();
+
+        let left_slice = left.slice(0, 2);
+        let right_slice = right.slice(2, 2);
+        let output = concat_elements_binary(
+            left_slice
+                .as_any()
+                .downcast_ref::<LargeBinaryArray>()
+                .unwrap(),
+            right_slice
+                .as_any()
+                .downcast_ref::<LargeBinaryArray>()
+                .unwrap(),
+        )
+        .unwrap();
+
+        let expected = [Some(b"\x00\x01\x03".as_ref()), Some(b"\xff\x04")]
+            .into_iter()
+            .collect::<LargeBinaryArray>();
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_binary_concat_error() {
+        let left = BinaryArray::from_vec(vec![b"foo", b"bar"]);
+        let right = BinaryArray::from_vec(vec![b"baz"]);
+
+        let output = concat_elements_binary(&left, &right);
+
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn test_string_concat_many() {
+        let dirs = [Some("usr"), Some("etc"), None, Some("home"), Some("")]
+            .into_iter()
+            .collect::<StringArray>();
+        let sep = StringArray::from(vec!["/"; 5]);
+        let files = [Some("bin"), Some("hosts"), Some("x"), None, Some("root")]
+            .into_iter()
+            .collect::<StringArray>();
+        let ext = [
+            Some(".txt"),
+            Some(""),
+            Some(".txt"),
+            Some(".txt"),
+            Some(".rs"),
+        ]
+        .into_iter()
+        .collect::<StringArray>();
+
+        let output = concat_elements_utf8_many(&[&dirs, &sep, &files, &ext]).unwrap();
+
+        let expected = (0..5)
+            .map(|i| {
+                [&dirs, &sep, &files, &ext]
+                    .iter()
+                    .map(|a| a.is_valid(i).then(|| a.value(i)))
+                    .collect::<Option<String>>()
+            })
+            .collect::<StringArray>();
+        assert_eq!(output, expected);
+
+        let expected = [
+            Some("usr/bin.txt"),
+            Some("etc/hosts"),
+            None,
+            None,
+            Some("/root.rs"),
+        ]
+        .into_iter()
+        .collect::<StringArray>();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_string_concat_many_slice() {
+        let left = StringArray::from(vec![Some("a"), None, Some("b"), Some("c")]);
+        let right = StringArray::from(vec![Some("d"), Some("e"), Some("f"), Some("g")]);
+        let left = left.slice(2, 2);
+        let right = right.slice(1, 2);
+        let left = left.as_any().downcast_ref::<StringArray>().unwrap();
+        let right = right.as_any().downcast_ref::<StringArray>().unwrap();
+
+        let output = concat_elements_utf8_many(&[left, right, left]).unwrap();
+
+        let expected = StringArray::from(vec!["beb", "cfc"]);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_string_concat_many_error() {
+        let output = concat_elements_utf8_many::<i32>(&[]);
+        assert!(output.is_err());
+
+        let left = StringArray::from(vec!["foo", "bar"]);
+        let right = StringArray::from(vec!["baz"]);
+        let output = concat_elements_utf8_many(&[&left, &left, &right]);
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn test_string_concat_with_separator() {
+        let left = [Some("foo"), Some(""), None, Some("bar"), Some("")]
+            .into_iter()
+            .collect::<StringArray>();
+        let right = [Some("baz"), Some("qux"), Some("zzz"), None, Some("")]
+            .into_iter()
+            .collect::<StringArray>();
+
+        let output = concat_elements_utf8_with_separator(&left, &right, ",").unwrap();
+
+        let expected = [Some("foo,baz"), Some(",qux"), None, None, Some(",")]
+            .into_iter()
+            .collect::<StringArray>();
+        assert_eq!(output, expected);
+        // null slots do not contain a separator
+        assert_eq!(output.value_offsets(), &[0, 7, 11, 11, 11, 12]);
+    }
+
+    #[test]
+    fn test_string_concat_with_empty_separator() {
+        let left = LargeStringArray::from(vec![Some("foo"), None, Some("")]);
+        let right = LargeStringArray::from(vec![Some("bar"), Some("baz"), Some("")]);
+
+        let output = concat_elements_utf8_with_separator(&left, &right, "").unwrap();
+
+        let expected = concat_elements_utf8(&left, &right).unwrap();
+        assert_eq!(output, expected);
+        assert_eq!(
+            output,
+            LargeStringArray::from(vec![Some("foobar"), None, Some("")])
+        );
+
+        let right = LargeStringArray::from(vec!["bar"]);
+        let output = concat_elements_utf8_with_separator(&left, &right, "");
+        assert!(output.is_err());
+    }
+
+    #[test]
+    #[cfg(not(feature = "force_validate"))]
+    fn test_string_concat_with_separator_overflow() {
+        // zeroed value buffers are lazily allocated, so this is cheap
+        let values = Buffer::from(MutableBuffer::from_len_zeroed(1 << 30));
+        let data = unsafe {
+            ArrayData::builder(DataType::Utf8)
+                .len(1)
+                .add_buffer(Buffer::from_slice_ref(&[0, 1 << 30]))
+                .add_buffer(values)
+                .build_unchecked()
+        };
+        let array = StringArray::from(data);
+
+        let error = concat_elements_utf8_with_separator(&array, &array, ",").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Compute error: concat would produce more value bytes than the offsets of Utf8 can hold"
+        );
+    }
+
+    #[test]
+    fn test_string_concat_coalesce() {
+        let left = [Some("foo"), None, Some("bar"), None, Some("")]
+            .into_iter()
+            .collect::<StringArray>();
+        let right = [None, Some("baz"), Some("qux"), None, None]
+            .into_iter()
+            .collect::<StringArray>();
+
+        let output = concat_elements_utf8_coalesce(&left, &right).unwrap();
+
+        let expected = [Some("foo"), Some("baz"), Some("barqux"), None, Some("")]
+            .into_iter()
+            .collect::<StringArray>();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_string_concat_coalesce_no_null() {
+        let left = LargeStringArray::from(vec![Some("foo"), None, Some("bar")]);
+        let right = LargeStringArray::from(vec!["a", "b", "c"]).slice(0, 3);
+        let right = right.as_any().downcast_ref::<LargeStringArray>().unwrap();
+
+        let output = concat_elements_utf8_coalesce(&left, right).unwrap();
+
+        let expected = LargeStringArray::from(vec!["fooa", "b", "barc"]);
+        assert_eq!(output, expected);
+        assert_eq!(output.null_count(), 0);
+
+        let right = LargeStringArray::from(vec!["a"]);
+        assert!(concat_elements_utf8_coalesce(&left, &right).is_err());
+    }
+
+    #[test]
+    fn test_string_repeat() {
+        let values = StringArray::from(vec![
+            Some("ab"),
+            Some("c"),
+            None,
+            Some("d"),
+            Some("ef"),
+            Some("こんにちは"),
+        ]);
+        let counts =
+            Int32Array::from(vec![Some(2), Some(0), Some(1), None, Some(-1), Some(3)]);
+
+        let output = repeat_utf8(&values, &counts).unwrap();
+
+        let expected = StringArray::from(vec![
+            Some("abab"),
+            Some(""),
+            None,
+            None,
+            Some(""),
+            Some("こんにちはこんにちはこんにちは"),
+        ]);
+        assert_eq!(output, expected);
+        // the values buffer is sized exactly
+        assert_eq!(output.value_offsets()[6], 49);
+    }
+
+    #[test]
+    fn test_string_repeat_sliced() {
+        let values = LargeStringArray::from(vec!["a", "bc", "d"]);
+        let values = values.slice(1, 2);
+        let values = values.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        let counts = Int32Array::from(vec![Some(4), Some(2), None]);
+        let counts = counts.slice(1, 2);
+        let counts = counts.as_any().downcast_ref::<Int32Array>().unwrap();
+
+        let output = repeat_utf8(values, counts).unwrap();
+        assert_eq!(output, LargeStringArray::from(vec![Some("bcbc"), None]));
+
+        let error = repeat_utf8(values, &Int32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Compute error: Arrays must have the same length: 2 != 1"
+        );
+    }
+
+    #[test]
+    fn test_substring_concat() {
+        let left = StringArray::from(vec![
+            Some("hello"),
+            Some("ab"),
+            None,
+            Some("héllo wörld"),
+            Some("x"),
+        ]);
+        let right =
+            StringArray::from(vec![Some("!"), Some("?"), Some("x"), None, Some("")]);
+
+        let output = substring_concat(&left, 0, Some(3), &right).unwrap();
+        let expected =
+            StringArray::from(vec![Some("hel!"), Some("ab?"), None, None, Some("x")]);
+        assert_eq!(output, expected);
+
+        // multi-byte characters are counted as one
+        let right = StringArray::from(vec!["-"; 5]);
+        let output = substring_concat(&left, 1, Some(6), &right).unwrap();
+        let expected = StringArray::from(vec![
+            Some("ello-"),
+            Some("b-"),
+            None,
+            Some("éllo w-"),
+            Some("-"),
+        ]);
+        assert_eq!(output, expected);
+
+        // negative starts count from the end, out of range starts are clamped
+        let output = substring_concat(&left, -3, None, &right).unwrap();
+        let expected = StringArray::from(vec![
+            Some("llo-"),
+            Some("ab-"),
+            None,
+            Some("rld-"),
+            Some("x-"),
+        ]);
+        assert_eq!(output, expected);
+        let output = substring_concat(&left, 10, Some(2), &right).unwrap();
+        let expected =
+            StringArray::from(vec![Some("-"), Some("-"), None, Some("d-"), Some("-")]);
+        assert_eq!(output, expected);
+
+        let error =
+            substring_concat(&left, 0, None, &StringArray::from(vec!["a"])).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Compute error: Arrays must have the same length: 5 != 1"
+        );
+    }
+
+    #[test]
+    fn test_substring_concat_sliced() {
+        let left =
+            LargeStringArray::from(vec![Some("skip"), Some("arrow"), Some("rust")]);
+        let left = left.slice(1, 2);
+        let left = left.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        let right = LargeStringArray::from(vec![Some("s"), None, Some("y")]);
+        let right = right.slice(1, 2);
+        let right = right.as_any().downcast_ref::<LargeStringArray>().unwrap();
+
+        let output = substring_concat(left, 1, Some(3), right).unwrap();
+        assert_eq!(output, LargeStringArray::from(vec![None, Some("usty")]));
+    }
+
+    #[test]
+    fn test_string_lpad() {
+        let values = StringArray::from(vec![
+            Some("ab"),
+            Some("abcd"),
+            None,
+            Some("a"),
+            Some("héllo"),
+            Some(""),
+            Some("x"),
+        ]);
+        let lengths = Int32Array::from(vec![
+            Some(4),
+            Some(2),
+            Some(3),
+            None,
+            Some(7),
+            Some(3),
+            Some(-1),
+        ]);
+
+        let output = lpad_utf8(&values, &lengths, "xy").unwrap();
+        let expected = StringArray::from(vec![
+            Some("xyab"),
+            Some("ab"),
+            None,
+            None,
+            Some("xyhéllo"),
+            Some("xyx"),
+            Some(""),
+        ]);
+        assert_eq!(output, expected);
+
+        // multi-byte padding, truncated on characters
+        let output = lpad_utf8(&values, &lengths, "éà").unwrap();
+        let expected = StringArray::from(vec![
+            Some("éàab"),
+            Some("ab"),
+            None,
+            None,
+            Some("éàhéllo"),
+            Some("éàé"),
+            Some(""),
+        ]);
+        assert_eq!(output, expected);
+
+        // an empty pad only truncates
+        let output = lpad_utf8(&values, &lengths, "").unwrap();
+        let expected = StringArray::from(vec![
+            Some("ab"),
+            Some("ab"),
+            None,
+            None,
+            Some("héllo"),
+            Some(""),
+            Some(""),
+        ]);
+        assert_eq!(output, expected);
+
+        let error = lpad_utf8(&values, &Int32Array::from(vec![1]), " ").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Compute error: Arrays must have the same length: 7 != 1"
+        );
+    }
+
+    #[test]
+    fn test_string_rpad() {
+        let values =
+            LargeStringArray::from(vec![Some("x"), Some("ab"), None, Some("héllo")]);
+        let values = values.slice(1, 3);
+        let values = values.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        let lengths = Int32Array::from(vec![Some(5), Some(4), None]);
+
+        let output = rpad_utf8(values, &lengths, "xy").unwrap();
+        assert_eq!(
+            output,
+            LargeStringArray::from(vec![Some("abxyx"), None, None])
+        );
+
+        let output = rpad_utf8_scalar(values, 3, "-").unwrap();
+        assert_eq!(
+            output,
+            LargeStringArray::from(vec![Some("ab-"), None, Some("hél")])
+        );
+
+        let output = lpad_utf8_scalar(values, 6, "*").unwrap();
+        assert_eq!(
+            output,
+            LargeStringArray::from(vec![Some("****ab"), None, Some("*héllo")])
+        );
+    }
+
+    #[test]
+    fn test_concat_elements_dyn() {
+        let left = LargeStringArray::from(vec![Some("foo"), None, Some("baz")]);
+        let right = LargeStringArray::from(vec![Some("bar"), Some("x"), Some("")]);
+        let output = concat_elements_dyn(&left, &right).unwrap();
+        let expected = concat_elements_utf8(&left, &right).unwrap();
+        assert_eq!(output.as_ref(), &expected as &dyn Array);
+
+        let left = StringArray::from(vec!["a", "b"]);
+        let right = StringArray::from(vec![None, Some("c")]);
+        let output = concat_elements_dyn(&left, &right).unwrap();
+        let expected = concat_elements_utf8(&left, &right).unwrap();
+        assert_eq!(output.as_ref(), &expected as &dyn Array);
+
+        let left = BinaryArray::from(vec![b"a".as_ref(), b"b"]);
+        let right = BinaryArray::from(vec![b"c".as_ref(), b""]);
+        let output = concat_elements_dyn(&left, &right).unwrap();
+        let expected = concat_elements_binary(&left, &right).unwrap();
+        assert_eq!(output.as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_concat_elements_dyn_error() {
+        let left = StringArray::from(vec!["a"]);
+        let right = LargeStringArray::from(vec!["b"]);
+        let output = concat_elements_dyn(&left, &right);
+        assert_eq!(
+            output.unwrap_err().to_string(),
+            "Compute error: Cannot concat elements of arrays of different types: Utf8 != LargeUtf8"
+        );
+
+        let array = Int32Array::from(vec![1]);
+        let output = concat_elements_dyn(&array, &array);
+        assert_eq!(
+            output.unwrap_err().to_string(),
+            "Compute error: Cannot concat elements of arrays of type Int32"
+        );
+    }
 }
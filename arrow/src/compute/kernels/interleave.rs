@@ -0,0 +1,233 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines interleave kernel for `ArrayRef`
+
+use crate::array::*;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// Takes elements by index from a list of [`Array`], creating a new [`Array`] from those values.
+///
+/// Each element in `indices` is a pair of `usize` with the first identifying the index
+/// of the [`Array`] in `values`, and the second the index of the value within that [`Array`]
+///
+/// ```text
+/// ┌─────────────────┐      ┌─────────┐                                  ┌─────────────────┐
+/// │        A        │      │ (0, 0)  │        interleave(               │        A        │
+/// ├─────────────────┤      ├─────────┤          [values0, values1],     ├─────────────────┤
+/// │        D        │      │ (1, 0)  │          [(0, 0), (1, 0), (1, 1)]│        B        │
+/// └─────────────────┘      ├─────────┤        )                         ├─────────────────┤
+///   values array 0         │ (1, 1)  │      ─────────────────────────▶  │        C        │
+///                          └─────────┘                                  └─────────────────┘
+/// ┌─────────────────┐       indices
+/// │        B        │        array
+/// ├─────────────────┤                                                    result
+/// │        C        │
+/// ├─────────────────┤
+/// │        E        │
+/// └─────────────────┘
+///   values array 1
+/// ```
+///
+/// An error is returned if `values` is empty, if the arrays have different
+/// data types, or if any of `indices` is out of bounds.
+pub fn interleave(values: &[&dyn Array], indices: &[(usize, usize)]) -> Result<ArrayRef> {
+    if values.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "interleave requires input of at least one array".to_string(),
+        ));
+    }
+    let data_type = values[0].data_type();
+
+    if values
+        .iter()
+        .skip(1)
+        .any(|array| array.data_type() != data_type)
+    {
+        return Err(ArrowError::InvalidArgumentError(
+            "It is not possible to interleave arrays of different data types."
+                .to_string(),
+        ));
+    }
+
+    if let Some((array, row)) = indices
+        .iter()
+        .find(|(array, row)| *array >= values.len() || *row >= values[*array].len())
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "interleave index ({}, {}) is out of bounds",
+            array, row
+        )));
+    }
+
+    if indices.is_empty() {
+        return Ok(new_empty_array(data_type));
+    }
+
+    let arrays = values.iter().map(|x| x.data()).collect::<Vec<_>>();
+    let capacities = match data_type {
+        DataType::Utf8 | DataType::Binary => Capacities::Binary(
+            indices.len(),
+            Some(values_length::<i32>(&arrays, indices)),
+        ),
+        DataType::LargeUtf8 | DataType::LargeBinary => Capacities::Binary(
+            indices.len(),
+            Some(values_length::<i64>(&arrays, indices)),
+        ),
+        _ => Capacities::Array(indices.len()),
+    };
+    let mut mutable = MutableArrayData::with_capacities(arrays, false, capacities);
+
+    // extend contiguous runs of rows of the same array at once
+    let mut cur_array = indices[0].0;
+    let mut start_row_idx = indices[0].1;
+    let mut end_row_idx = start_row_idx + 1;
+
+    for (array, row) in indices.iter().skip(1).copied() {
+        if array == cur_array && row == end_row_idx {
+            end_row_idx += 1;
+            continue;
+        }
+        mutable.extend(cur_array, start_row_idx, end_row_idx);
+        cur_array = array;
+        start_row_idx = row;
+        end_row_idx = start_row_idx + 1;
+    }
+    mutable.extend(cur_array, start_row_idx, end_row_idx);
+
+    Ok(make_array(mutable.freeze()))
+}
+
+/// Returns the number of value bytes of the rows of `arrays` selected by `indices`
+fn values_length<Offset: OffsetSizeTrait>(
+    arrays: &[&ArrayData],
+    indices: &[(usize, usize)],
+) -> usize {
+    indices
+        .iter()
+        .map(|(array, row)| {
+            let offsets = arrays[*array].buffer::<Offset>(0);
+            (offsets[row + 1] - offsets[*row]).to_usize().unwrap()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::Int32Type;
+
+    #[test]
+    fn test_interleave_primitive() {
+        let a = Int32Array::from(vec![1, 2, 3, 4]);
+        let b = Int32Array::from(vec![Some(5), None, Some(7)]);
+
+        let values =
+            interleave(&[&a, &b], &[(0, 3), (0, 3), (1, 1), (1, 2), (0, 0)]).unwrap();
+        let expected = Int32Array::from(vec![Some(4), Some(4), None, Some(7), Some(1)]);
+        assert_eq!(values.as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_interleave_strings() {
+        let a = StringArray::from(vec!["a", "b", "c"]);
+        let b = StringArray::from(vec![Some("hello"), None, Some("world")]);
+        let c = StringArray::from(vec!["x", "y", "z"]).slice(1, 2);
+        let indices = [
+            (2, 1),
+            (0, 0),
+            (1, 2),
+            (1, 1),
+            (2, 0),
+            (0, 1),
+            (0, 2),
+            (1, 0),
+        ];
+
+        let values = interleave(&[&a, &b, c.as_ref()], &indices).unwrap();
+        let expected = StringArray::from(vec![
+            Some("z"),
+            Some("a"),
+            Some("world"),
+            None,
+            Some("y"),
+            Some("b"),
+            Some("c"),
+            Some("hello"),
+        ]);
+        assert_eq!(values.as_ref(), &expected as &dyn Array);
+        // "zaworldybchello" is 15 bytes, tightly allocated
+        assert_eq!(values.data().buffers()[1].capacity(), 64);
+    }
+
+    #[test]
+    fn test_interleave_lists() {
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+        ]);
+        let b =
+            ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(3)])]);
+
+        let values = interleave(&[&a, &b], &[(0, 1), (1, 0), (0, 0)]).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            None,
+            Some(vec![Some(3)]),
+            Some(vec![Some(1), Some(2)]),
+        ]);
+        assert_eq!(values.as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_interleave_empty_indices() {
+        let a = Int32Array::from(vec![1, 2]);
+        let values = interleave(&[&a], &[]).unwrap();
+        assert_eq!(values.data_type(), a.data_type());
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    fn test_interleave_errors() {
+        let a = Int32Array::from(vec![1, 2]);
+        let b = StringArray::from(vec!["a"]);
+
+        let err = interleave(&[], &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: interleave requires input of at least one array"
+        );
+
+        let err = interleave(&[&a, &b], &[(0, 0)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: It is not possible to interleave arrays of different data types."
+        );
+
+        let err = interleave(&[&a, &a], &[(0, 0), (1, 2)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: interleave index (1, 2) is out of bounds"
+        );
+
+        let err = interleave(&[&a, &a], &[(2, 0)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: interleave index (2, 0) is out of bounds"
+        );
+    }
+}
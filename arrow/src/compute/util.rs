@@ -55,7 +55,9 @@ pub(super) fn combine_option_bitmap(
             Err(ArrowError::ComputeError(
                 "Arrays must not be empty".to_string(),
             )),
-            |(buffer, offset)| Ok(buffer.map(|buffer| buffer.slice(offset))),
+            |(buffer, offset)| {
+                Ok(buffer.map(|buffer| buffer.bit_slice(offset, len_in_bits)))
+            },
         )
 }
 
@@ -254,6 +256,14 @@ pub(super) mod tests {
             )
             .unwrap()
         );
+        assert_eq!(
+            Some(Buffer::from([0b00001001])),
+            combine_option_bitmap(
+                &[&some_bitmap.slice(3, 5), &none_bitmap.slice(1, 5)],
+                5
+            )
+            .unwrap()
+        );
     }
 
     #[test]
@@ -156,6 +156,12 @@ impl Field {
         self.nullable
     }
 
+    /// Sets whether this `Field` supports null values.
+    #[inline]
+    pub fn set_nullable(&mut self, nullable: bool) {
+        self.nullable = nullable;
+    }
+
     /// Returns a (flattened) vector containing all fields contained within this field (including it self)
     pub(crate) fn fields(&self) -> Vec<&Field> {
         let mut collected_fields = vec![self];
@@ -21,7 +21,7 @@
 use std::sync::Arc;
 
 use crate::array::*;
-use crate::compute::kernels::concat::concat;
+use crate::compute::kernels::concat::concat_batches;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 
@@ -381,32 +381,10 @@ impl RecordBatch {
     }
 
     /// Concatenates `batches` together into a single record batch.
+    ///
+    /// See [`concat_batches`] for details.
     pub fn concat(schema: &SchemaRef, batches: &[Self]) -> Result<Self> {
-        if batches.is_empty() {
-            return Ok(RecordBatch::new_empty(schema.clone()));
-        }
-        if let Some((i, _)) = batches
-            .iter()
-            .enumerate()
-            .find(|&(_, batch)| batch.schema() != *schema)
-        {
-            return Err(ArrowError::InvalidArgumentError(format!(
-                "batches[{}] schema is different with argument schema.",
-                i
-            )));
-        }
-        let field_num = schema.fields().len();
-        let mut arrays = Vec::with_capacity(field_num);
-        for i in 0..field_num {
-            let array = concat(
-                &batches
-                    .iter()
-                    .map(|batch| batch.column(i).as_ref())
-                    .collect::<Vec<_>>(),
-            )?;
-            arrays.push(array);
-        }
-        Self::try_new(schema.clone(), arrays)
+        concat_batches(schema, batches)
     }
 }
 